@@ -5,7 +5,31 @@ use bevy::tasks::futures_lite::StreamExt as _;
 use bevy::tasks::{IoTaskPool, Task};
 use bevy::utils::synccell::SyncCell;
 use bevy::winit::{EventLoopProxy, EventLoopProxyWrapper, WakeUp, WinitPlugin};
-use mundy::{AccentColor, Interest, Preferences, PreferencesStream};
+#[cfg(feature = "accent-color")]
+use mundy::AccentColor;
+#[cfg(feature = "caret-blink-interval")]
+use mundy::CaretBlinkInterval;
+#[cfg(feature = "color-scheme")]
+use mundy::ColorScheme;
+#[cfg(feature = "contrast")]
+use mundy::Contrast;
+#[cfg(feature = "double-click-interval")]
+use mundy::DoubleClickInterval;
+#[cfg(feature = "reduced-motion")]
+use mundy::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use mundy::ReducedTransparency;
+#[cfg(feature = "system-colors")]
+use mundy::SystemColors;
+#[cfg(feature = "system-palette")]
+use mundy::SystemPalette;
+#[cfg(feature = "text-scale-factor")]
+use mundy::TextScaleFactor;
+#[cfg(feature = "time-format")]
+use mundy::TimeFormat;
+#[cfg(feature = "ui-scale-factor")]
+use mundy::UiScaleFactor;
+use mundy::{Interest, Preferences, PreferencesStream};
 use std::marker::PhantomData;
 
 pub use mundy;
@@ -21,6 +45,16 @@ pub struct PreferencesPlugin<E: 'static = WakeUp> {
     /// the window loop when running in reactive mode.
     /// *Default:* `|| WakeUp`
     pub wakeup: fn() -> E,
+    /// Whether [`WinitPlugin`] must be present before this plugin builds.
+    ///
+    /// When `true` (the default), `build` panics unless `WinitPlugin` has already been
+    /// added, and preference updates wake up the window event loop through an
+    /// [`EventLoopProxy`] so reactive winit apps still notice them. Set this to `false`
+    /// to run in a headless app (no window, no winit event loop — e.g. a server, CI, or a
+    /// custom runner): the wakeup call is skipped and preferences are instead picked up
+    /// every `PreUpdate` through normal continuous schedule polling.
+    /// *Default:* `true`
+    pub require_winit: bool,
 }
 
 impl Default for PreferencesPlugin {
@@ -28,6 +62,7 @@ impl Default for PreferencesPlugin {
         PreferencesPlugin {
             interest: Interest::All,
             wakeup: || WakeUp,
+            require_winit: true,
         }
     }
 }
@@ -36,8 +71,11 @@ impl<E: 'static + Send> Plugin for PreferencesPlugin<E> {
     fn build(&self, app: &mut App) {
         // If we create our stream *before* winit is initialized,
         // we'll get a panic on macOS: https://github.com/rust-windowing/winit/issues/3772
-        if !app.is_plugin_added::<WinitPlugin>() {
-            panic!("WinitPlugin needs to be added before PreferencesPlugin")
+        if self.require_winit && !app.is_plugin_added::<WinitPlugin>() {
+            panic!(
+                "WinitPlugin needs to be added before PreferencesPlugin, \
+                 or `PreferencesPlugin::require_winit` needs to be set to `false`"
+            )
         }
 
         let stream = Preferences::stream(self.interest);
@@ -50,14 +88,51 @@ impl<E: 'static + Send> Plugin for PreferencesPlugin<E> {
             PreUpdate,
             (poll_receiver, update_preferences_resource).chain(),
         );
+        register_types(app);
     }
 }
 
+/// Registers [`PreferencesRes`], [`Preferences`] and every enabled preference type
+/// (plus [`mundy::Srgba`]) with the [`AppTypeRegistry`](bevy::ecs::reflect::AppTypeRegistry)
+/// so that tools like `bevy-inspector-egui` and Bevy's scene serialization can see them.
+fn register_types(app: &mut App) {
+    app.register_type::<PreferencesRes>();
+    app.register_type::<Preferences>();
+    app.register_type::<mundy::Srgba>();
+    #[cfg(feature = "accent-color")]
+    app.register_type::<mundy::WideGamutColor>();
+    #[cfg(feature = "color-scheme")]
+    app.register_type::<ColorScheme>();
+    #[cfg(feature = "contrast")]
+    app.register_type::<Contrast>();
+    #[cfg(feature = "reduced-motion")]
+    app.register_type::<ReducedMotion>();
+    #[cfg(feature = "reduced-transparency")]
+    app.register_type::<ReducedTransparency>();
+    #[cfg(feature = "accent-color")]
+    app.register_type::<AccentColor>();
+    #[cfg(feature = "double-click-interval")]
+    app.register_type::<DoubleClickInterval>();
+    #[cfg(feature = "time-format")]
+    app.register_type::<TimeFormat>();
+    #[cfg(feature = "system-colors")]
+    app.register_type::<SystemColors>();
+    #[cfg(feature = "caret-blink-interval")]
+    app.register_type::<CaretBlinkInterval>();
+    #[cfg(feature = "text-scale-factor")]
+    app.register_type::<TextScaleFactor>();
+    #[cfg(feature = "ui-scale-factor")]
+    app.register_type::<UiScaleFactor>();
+    #[cfg(feature = "system-palette")]
+    app.register_type::<SystemPalette>();
+}
+
 impl<E: 'static> PreferencesPlugin<E> {
     pub fn with_custom_event<F>(self, wakeup: fn() -> F) -> PreferencesPlugin<F> {
         PreferencesPlugin {
             interest: self.interest,
             wakeup,
+            require_winit: self.require_winit,
         }
     }
 
@@ -65,12 +140,17 @@ impl<E: 'static> PreferencesPlugin<E> {
         self.interest = interest;
         self
     }
+
+    pub fn with_require_winit(mut self, require_winit: bool) -> Self {
+        self.require_winit = require_winit;
+        self
+    }
 }
 
 fn spawn_task<E: 'static + Send>(
     mut commands: Commands,
     mut stream: ResMut<PreferencesStreamRes>,
-    event_loop_proxy: Res<EventLoopProxyWrapper<E>>,
+    event_loop_proxy: Option<Res<EventLoopProxyWrapper<E>>>,
     wakeup: Res<WakeupEvent<E>>,
 ) {
     let stream = (stream.0)
@@ -78,7 +158,8 @@ fn spawn_task<E: 'static + Send>(
         .take()
         .expect("plugin ensures that pref stream exists");
     let (sender, receiver) = unbounded();
-    let task = forward_preferences(stream, sender, event_loop_proxy.clone(), wakeup.0);
+    let event_loop_proxy = event_loop_proxy.map(|proxy| proxy.clone());
+    let task = forward_preferences(stream, sender, event_loop_proxy, wakeup.0);
     commands.insert_resource(PreferencesSubscription { receiver, task });
     commands.remove_resource::<PreferencesStreamRes>();
     commands.remove_resource::<WakeupEvent<E>>();
@@ -105,18 +186,23 @@ fn update_preferences_resource(
 fn forward_preferences<E: 'static + Send>(
     mut stream: PreferencesStream,
     sender: Sender<Preferences>,
-    event_loop_proxy: EventLoopProxy<E>,
+    event_loop_proxy: Option<EventLoopProxy<E>>,
     wakeup_event: fn() -> E,
 ) -> Task<()> {
     IoTaskPool::get().spawn(async move {
         while let Some(preferences) = stream.next().await {
             _ = sender.send(preferences).await;
-            _ = event_loop_proxy.send_event(wakeup_event());
+            // In headless mode (no winit event loop to wake up) preferences are instead
+            // picked up by `poll_receiver` every `PreUpdate` through normal scheduling.
+            if let Some(event_loop_proxy) = &event_loop_proxy {
+                _ = event_loop_proxy.send_event(wakeup_event());
+            }
         }
     })
 }
 
-#[derive(Resource, Default, Debug, Clone)]
+#[derive(Resource, Reflect, Default, Debug, Clone)]
+#[reflect(Resource)]
 pub struct PreferencesRes(pub Preferences);
 
 #[derive(Event, Debug, Clone)]
@@ -150,10 +236,56 @@ trait Preference {
     fn from_preferences(preferences: &Preferences) -> Self;
 }
 
-impl Preference for AccentColor {
-    fn from_preferences(preferences: &Preferences) -> Self {
-        preferences.accent_color
-    }
+/// Implements [`Preference`] for a preference type, plus a run condition that
+/// returns `true` only on the frame(s) where that specific sub-preference
+/// actually changed, rather than on every [`PreferencesChanged`] event.
+macro_rules! impl_preference {
+    ($($feature:literal $changed:ident $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Preference for $ty {
+                fn from_preferences(preferences: &Preferences) -> Self {
+                    preferences.$field
+                }
+            }
+
+            #[cfg(feature = $feature)]
+            #[doc = concat!(
+                "A run condition that returns `true` only on the frame(s) where `",
+                stringify!($field),
+                "` actually changed."
+            )]
+            pub fn $changed(
+                mut events: EventReader<PreferencesChanged>,
+                mut last: Local<Option<$ty>>,
+            ) -> bool {
+                let mut changed = false;
+                for event in events.read() {
+                    let current = $ty::from_preferences(&event.0);
+                    if *last != Some(current) {
+                        changed = true;
+                        *last = Some(current);
+                    }
+                }
+                changed
+            }
+        )*
+    };
+}
+
+impl_preference! {
+    "color-scheme" color_scheme_changed color_scheme: ColorScheme,
+    "contrast" contrast_changed contrast: Contrast,
+    "reduced-motion" reduced_motion_changed reduced_motion: ReducedMotion,
+    "reduced-transparency" reduced_transparency_changed reduced_transparency: ReducedTransparency,
+    "accent-color" accent_color_changed accent_color: AccentColor,
+    "double-click-interval" double_click_interval_changed double_click_interval: DoubleClickInterval,
+    "time-format" time_format_changed time_format: TimeFormat,
+    "system-colors" system_colors_changed system_colors: SystemColors,
+    "caret-blink-interval" caret_blink_interval_changed caret_blink_interval: CaretBlinkInterval,
+    "text-scale-factor" text_scale_factor_changed text_scale_factor: TextScaleFactor,
+    "ui-scale-factor" ui_scale_factor_changed ui_scale_factor: UiScaleFactor,
+    "system-palette" system_palette_changed system_palette: SystemPalette,
 }
 
 #[derive(Resource)]
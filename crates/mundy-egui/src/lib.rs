@@ -0,0 +1,106 @@
+//! Applies a [`mundy::Preferences`] snapshot to an [`egui::Context`], so apps don't have
+//! to hand-roll the mapping from OS preferences to egui's [`Style`]/[`Options`] themselves.
+
+use eframe::egui::{self, style::Selection, Color32, Stroke, Style};
+use mundy::{
+    ColorScheme, DoubleClickInterval, Interest, Preferences, ReducedMotion, ReducedTransparency,
+    Subscription,
+};
+
+/// Applies every preference in `preferences` that this crate knows how to map
+/// onto `ctx`'s style/options, then requests a repaint so the change is visible
+/// immediately.
+pub fn apply_preferences(ctx: &egui::Context, preferences: &Preferences) {
+    ctx.all_styles_mut(|style| apply_style(style, preferences));
+    apply_double_click_interval(ctx, preferences.double_click_interval);
+    ctx.request_repaint();
+}
+
+/// Subscribes to every preference mundy supports and keeps `ctx` in sync with
+/// the OS as they change. Drop the returned [`Subscription`] to stop.
+pub fn subscribe(ctx: egui::Context) -> Subscription {
+    Preferences::subscribe(Interest::All, move |preferences| {
+        apply_preferences(&ctx, &preferences)
+    })
+}
+
+fn apply_style(style: &mut Style, preferences: &Preferences) {
+    apply_color_scheme(style, preferences.color_scheme);
+    if let Some(accent) = preferences.accent_color.0 {
+        // egui's style doesn't support wide-gamut colors, so gamut-map down to sRGB.
+        apply_accent_color(style, accent.to_srgba());
+    }
+    apply_reduced_transparency(style, preferences.reduced_transparency);
+    apply_reduced_motion(style, preferences.reduced_motion);
+}
+
+fn apply_color_scheme(style: &mut Style, color_scheme: ColorScheme) {
+    match color_scheme {
+        ColorScheme::Dark => style.visuals = egui::Visuals::dark(),
+        ColorScheme::Light => style.visuals = egui::Visuals::light(),
+        ColorScheme::NoPreference => {}
+    }
+}
+
+// This is the same accent tinting `egui_example` used to do inline, just promoted
+// to a reusable helper now that there's more than one preference to apply.
+fn apply_accent_color(style: &mut Style, accent: mundy::Srgba) {
+    use bevy_color::{ColorToComponents as _, Oklcha, Srgba};
+
+    let accent = Srgba::from_f32_array(accent.to_f64_array().map(|c| c as f32));
+    let accent = Oklcha::from(accent);
+    let hyperlink_lightness = if style.visuals.dark_mode { 0.7 } else { 0.5 };
+    let cursor_lightness = if style.visuals.dark_mode { 0.9 } else { 0.4 };
+    let sel_stroke = if style.visuals.dark_mode {
+        Color32::WHITE
+    } else {
+        Color32::BLACK
+    };
+    let sel_fill_lightness = if style.visuals.dark_mode { 0.3 } else { 0.9 };
+
+    style.visuals.hyperlink_color = to_color32(accent.with_lightness(hyperlink_lightness));
+    style.visuals.text_cursor.stroke.color = to_color32(accent.with_lightness(cursor_lightness));
+    style.visuals.selection = Selection {
+        bg_fill: to_color32(accent.with_lightness(sel_fill_lightness)),
+        stroke: Stroke {
+            color: sel_stroke,
+            ..style.visuals.selection.stroke
+        },
+    };
+
+    fn to_color32(color: impl Into<bevy_color::Srgba>) -> Color32 {
+        use bevy_color::ColorToPacked as _;
+        let color = color.into().to_u8_array();
+        Color32::from_rgba_premultiplied(color[0], color[1], color[2], color[3])
+    }
+}
+
+fn apply_reduced_transparency(style: &mut Style, reduced_transparency: ReducedTransparency) {
+    if !reduced_transparency.is_reduce() {
+        return;
+    }
+
+    fn opaque(color: Color32) -> Color32 {
+        Color32::from_rgb(color.r(), color.g(), color.b())
+    }
+
+    style.visuals.window_fill = opaque(style.visuals.window_fill);
+    style.visuals.panel_fill = opaque(style.visuals.panel_fill);
+    style.visuals.faint_bg_color = opaque(style.visuals.faint_bg_color);
+    style.visuals.extreme_bg_color = opaque(style.visuals.extreme_bg_color);
+}
+
+fn apply_reduced_motion(style: &mut Style, reduced_motion: ReducedMotion) {
+    if reduced_motion.is_reduce() {
+        // egui derives scroll/resize easing from `animation_time` too, so zeroing
+        // this one knob disables all of it in one go.
+        style.animation_time = 0.0;
+    }
+}
+
+fn apply_double_click_interval(ctx: &egui::Context, double_click_interval: DoubleClickInterval) {
+    if let Some(duration) = double_click_interval.0 {
+        ctx.options_mut(|options| options.max_double_click_delay = duration.as_secs_f64());
+    }
+}
+
@@ -0,0 +1,61 @@
+//! Bridges [`mundy::Preferences`] into a [`winit`] event loop.
+//!
+//! [`Preferences::subscribe`] invokes its callback on an internal background
+//! thread, which is unsafe for touching windows or other main-loop-owned state.
+//! [`PreferencesPump`] instead stashes incoming snapshots in a channel and wakes
+//! the event loop with a user event, so they can be drained safely from
+//! [`ApplicationHandler::user_event`](winit::application::ApplicationHandler::user_event)
+//! (or `about_to_wait`, if you'd rather poll) once that event arrives.
+
+use mundy::{ColorScheme, Interest, Preferences, Subscription};
+use std::sync::mpsc::{channel, Receiver};
+use winit::event_loop::EventLoopProxy;
+use winit::window::Theme;
+
+pub use mundy;
+
+/// Converts a [`ColorScheme`] into the closest matching winit [`Theme`].
+///
+/// Returns `None` for [`ColorScheme::NoPreference`], since winit has no
+/// "no preference" theme of its own.
+pub fn to_winit_theme(color_scheme: ColorScheme) -> Option<Theme> {
+    match color_scheme {
+        ColorScheme::Dark => Some(Theme::Dark),
+        ColorScheme::Light => Some(Theme::Light),
+        ColorScheme::NoPreference => None,
+    }
+}
+
+/// Subscribes to a selection of preferences and wakes `event_loop_proxy` with
+/// `wakeup()` every time a new snapshot is ready, instead of invoking a
+/// callback directly on mundy's subscription thread.
+///
+/// Call [`PreferencesPump::drain`] once your wakeup event arrives to pick up
+/// the latest snapshot.
+pub struct PreferencesPump<E: 'static + Send> {
+    receiver: Receiver<Preferences>,
+    _subscription: Subscription,
+    _wakeup: std::marker::PhantomData<fn() -> E>,
+}
+
+impl<E: 'static + Send> PreferencesPump<E> {
+    pub fn new(interest: Interest, event_loop_proxy: EventLoopProxy<E>, wakeup: fn() -> E) -> Self {
+        let (sender, receiver) = channel();
+        let subscription = Preferences::subscribe(interest, move |preferences| {
+            _ = sender.send(preferences);
+            _ = event_loop_proxy.send_event(wakeup());
+        });
+        Self {
+            receiver,
+            _subscription: subscription,
+            _wakeup: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the most recently received snapshot, if any arrived since the
+    /// last call. Intermediate snapshots received between calls are dropped;
+    /// only the latest one is returned.
+    pub fn drain(&self) -> Option<Preferences> {
+        self.receiver.try_iter().last()
+    }
+}
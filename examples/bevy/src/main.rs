@@ -37,7 +37,10 @@ fn update_materials(
 
         for entity in entities.iter() {
             if let Some(material) = materials.get_mut(&entity.0) {
-                let color = accent_color.0.map(Color::from).unwrap_or_default();
+                let color = accent_color
+                    .0
+                    .map(|c| Color::from(c.to_srgba()))
+                    .unwrap_or_default();
                 material.color = color;
             }
         }
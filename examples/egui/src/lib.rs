@@ -1,8 +1,7 @@
-use bevy_color::{ColorToComponents as _, ColorToPacked, Oklcha, Srgba};
-use eframe::egui::{self, style::Selection, Color32, Stroke, Style};
+use eframe::egui;
 use egui_demo_lib::{View as _, WidgetGallery};
 use egui_theme_switch::global_theme_switch;
-use mundy::{Interest, Preferences, Subscription};
+use mundy::Subscription;
 
 pub struct DemoApp {
     widget_gallery: WidgetGallery,
@@ -12,7 +11,7 @@ pub struct DemoApp {
 impl DemoApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         egui_extras::install_image_loaders(&cc.egui_ctx); // Needed for the "Widget Gallery" demo
-        let subscription = Preferences::subscribe(Interest::All, update_style(cc.egui_ctx.clone()));
+        let subscription = mundy_egui::subscribe(cc.egui_ctx.clone());
         Self {
             widget_gallery: WidgetGallery::default(),
             _subscription: subscription,
@@ -47,47 +46,6 @@ pub fn android_main(app: winit::platform::android::activity::AndroidApp) {
     .unwrap();
 }
 
-fn use_accent(style: &mut Style, accent: Srgba) {
-    let accent = Oklcha::from(accent);
-    let hyperlink_lightness = if style.visuals.dark_mode { 0.7 } else { 0.5 };
-    let cursor_lightness = if style.visuals.dark_mode { 0.9 } else { 0.4 };
-    let sel_stroke = if style.visuals.dark_mode {
-        Color32::WHITE
-    } else {
-        Color32::BLACK
-    };
-    let sel_fill_lightness = if style.visuals.dark_mode { 0.3 } else { 0.9 };
-
-    style.visuals.hyperlink_color = to_epaint(accent.with_lightness(hyperlink_lightness));
-    style.visuals.text_cursor.stroke.color = to_epaint(accent.with_lightness(cursor_lightness));
-    style.visuals.selection = Selection {
-        bg_fill: to_epaint(accent.with_lightness(sel_fill_lightness)),
-        stroke: Stroke {
-            color: sel_stroke,
-            ..style.visuals.selection.stroke
-        },
-    };
-}
-
-fn to_epaint(color: impl Into<Srgba>) -> Color32 {
-    let color = color.into().to_u8_array();
-    Color32::from_rgba_premultiplied(color[0], color[1], color[2], color[3])
-}
-
-fn to_bevy(color: mundy::Srgba) -> Srgba {
-    Srgba::from_f32_array(color.to_f64_array().map(|c| c as f32))
-}
-
-fn update_style(ctx: egui::Context) -> impl Fn(Preferences) {
-    move |preferences| {
-        log::info!("got new preferences: {preferences:#?}");
-        if let Some(accent) = preferences.accent_color.0 {
-            ctx.all_styles_mut(|style| use_accent(style, to_bevy(accent)));
-            ctx.request_repaint();
-        }
-    }
-}
-
 impl eframe::App for DemoApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Reserve some space at the top so the demo ui isn't hidden behind the android status bar
@@ -0,0 +1,39 @@
+//! Support for [`crate::platform::android::attach`] and
+//! [`crate::platform::android::on_main_event`], which let apps built on
+//! `android-activity` hand mundy the `AndroidApp` handle they already have and
+//! forward its `MainEvent::ConfigChanged` directly, instead of relying on a
+//! windowing toolkit's `ScaleFactorChanged` as a stand-in for it.
+
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+use android_activity::{AndroidApp, MainEvent};
+use std::sync::OnceLock;
+
+static ATTACHED_APP: OnceLock<AndroidApp> = OnceLock::new();
+
+pub(crate) fn attach(app: &AndroidApp) {
+    _ = ATTACHED_APP.set(app.clone());
+}
+
+pub(crate) fn on_main_event(event: &MainEvent) {
+    if matches!(event, MainEvent::ConfigChanged { .. }) {
+        super::subscription::on_configuration_changed();
+    }
+}
+
+/// The [`ColorScheme`] implied by the `uiMode` night-mode bits of the
+/// `AndroidApp` passed to [`attach`], if it's been called.
+///
+/// `android-activity`'s `Configuration` mirrors the NDK's `AConfiguration`,
+/// which carries `uiMode` straight from the OS, so reading it this way avoids
+/// the JNI round-trip `get_color_scheme` otherwise needs.
+#[cfg(feature = "color-scheme")]
+pub(crate) fn attached_color_scheme() -> Option<ColorScheme> {
+    use android_activity::configuration::UiModeNight;
+
+    match ATTACHED_APP.get()?.config().ui_mode_night() {
+        UiModeNight::Yes => Some(ColorScheme::Dark),
+        UiModeNight::No => Some(ColorScheme::Light),
+        UiModeNight::Undefined | UiModeNight::Unknown(_) => None,
+    }
+}
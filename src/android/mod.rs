@@ -38,9 +38,22 @@
 //!
 //! For settings like these, we rely on the user of mundy to call [`crate::platform::android::on_configuration_changed`].
 //!
+//! High contrast and reduced motion changes, on the other hand, don't go through
+//! activity re-creation at all, so there's no `onConfigurationChanged` callback to hook into in
+//! the first place. For those, and for accent color, the Java glue registers an `android.database.ContentObserver` on
+//! the relevant `Settings.Secure`/`Settings.Global` URIs and forwards `onChange` back into Rust
+//! through the same native callback used for everything else.
+//!
 //! Unfortunately, `winit` [does not provide][winit-missing-api] access to the `ConfigurationChanged` event.
 //! So apps relying on `winit` will not be able to detect dark/light mode changes.
 //!
+//! Apps built directly on [`android-activity`] don't have this problem: its `AndroidApp` carries
+//! the current `Configuration` (read natively, with no JNI round-trip) and emits
+//! `MainEvent::ConfigChanged` whenever it changes. [`crate::platform::android::attach`] and
+//! [`crate::platform::android::on_main_event`] hook into exactly that, so those apps can detect
+//! dark/light mode changes directly instead of needing a windowing toolkit's `ScaleFactorChanged`
+//! as a stand-in.
+//!
 //! [NDK]: https://developer.android.com/ndk/reference
 //! [`ndk`]: https://docs.rs/ndk/0.9.0/ndk/
 //! [`Context`]: https://developer.android.com/reference/android/content/Context
@@ -50,6 +63,7 @@
 //! [winit-missing-api]: https://github.com/rust-windowing/winit/issues/2120
 //! [`Destroy`]: https://docs.rs/android-activity/latest/android_activity/enum.MainEvent.html#variant.Destroy
 //! [`NativeActivity`]: https://developer.android.com/reference/android/app/NativeActivity
+//! [`android-activity`]: https://docs.rs/android-activity
 //! [`uiMode`]: https://developer.android.com/reference/android/content/res/Configuration#uiMode
 
 #[cfg(feature = "color-scheme")]
@@ -58,8 +72,14 @@ use crate::ColorScheme;
 use crate::Contrast;
 #[cfg(feature = "reduced-motion")]
 use crate::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
 #[cfg(feature = "accent-color")]
 use crate::{AccentColor, Srgba};
+#[cfg(feature = "text-scale-factor")]
+use crate::TextScaleFactor;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
 use crate::{AvailablePreferences, Interest};
 use futures_channel::mpsc;
 use futures_lite::{stream, Stream, StreamExt as _};
@@ -71,9 +91,11 @@ use support::{java_vm, JavaSupport};
 
 // signatures: <https://docs.oracle.com/javase/8/docs/technotes/guides/jni/spec/types.html>
 
+mod attach;
 mod result;
 mod subscription;
 mod support;
+pub(crate) use attach::{attach, on_main_event};
 pub(crate) use subscription::on_configuration_changed;
 
 pin_project! {
@@ -150,7 +172,10 @@ fn try_get_preferences(interest: Interest) -> Result<AvailablePreferences> {
 
     #[cfg(feature = "color-scheme")]
     if interest.is(Interest::ColorScheme) {
-        preferences.color_scheme = get_color_scheme(&support, &mut env).unwrap_or_default();
+        preferences.color_scheme = match attach::attached_color_scheme() {
+            Some(color_scheme) => color_scheme,
+            None => get_color_scheme(&support, &mut env).unwrap_or_default(),
+        };
     }
 
     #[cfg(feature = "contrast")]
@@ -163,11 +188,28 @@ fn try_get_preferences(interest: Interest) -> Result<AvailablePreferences> {
         preferences.reduced_motion = get_reduced_motion(&support, &mut env).unwrap_or_default();
     }
 
-    #[cfg(feature = "reduced-motion")]
+    #[cfg(feature = "accent-color")]
     if interest.is(Interest::AccentColor) {
         preferences.accent_color = get_accent_color(&support, &mut env).unwrap_or_default();
     }
 
+    #[cfg(feature = "reduced-transparency")]
+    if interest.is(Interest::ReducedTransparency) {
+        preferences.reduced_transparency =
+            get_reduced_transparency(&support, &mut env).unwrap_or_default();
+    }
+
+    #[cfg(feature = "text-scale-factor")]
+    if interest.is(Interest::TextScaleFactor) {
+        preferences.text_scale_factor =
+            get_text_scale_factor(&support, &mut env).unwrap_or_default();
+    }
+
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        preferences.time_format = get_time_format(&support, &mut env).unwrap_or_default();
+    }
+
     Ok(preferences)
 }
 
@@ -198,9 +240,15 @@ fn get_reduced_motion(support: &JavaSupport, env: &mut JNIEnv) -> Result<Reduced
     }
 }
 
-#[cfg(feature = "reduced-motion")]
+// Reads the Material You dynamic accent color (`android.R.color.system_accent1_500`);
+// on pre-Android-12 devices where the resource doesn't exist, the Java side falls
+// back to reporting `0` (no color), which we surface as `None` here too.
+#[cfg(feature = "accent-color")]
 fn get_accent_color(support: &JavaSupport, env: &mut JNIEnv) -> Result<AccentColor> {
     let color = support.get_accent_color(env)? as u32;
+    if color == 0 {
+        return Ok(AccentColor(None));
+    }
     // Color ints in Android APIs always define colors in the
     // sRGB color space, packed into an int as #AARRGGBB:
     // https://developer.android.com/reference/android/graphics/Color#color-ints
@@ -209,5 +257,82 @@ fn get_accent_color(support: &JavaSupport, env: &mut JNIEnv) -> Result<AccentCol
     let green = ((color >> 8) & 0xff) as u8;
     let blue = (color & 0xff) as u8;
     let color = Srgba::from_u8_array([red, green, blue, alpha]);
-    Ok(AccentColor(Some(color)))
+    Ok(AccentColor(Some(color.into())))
+}
+
+#[cfg(feature = "reduced-transparency")]
+fn get_reduced_transparency(
+    support: &JavaSupport,
+    env: &mut JNIEnv,
+) -> Result<ReducedTransparency> {
+    if support.get_reduced_transparency(env)? {
+        Ok(ReducedTransparency::Reduce)
+    } else {
+        Ok(ReducedTransparency::NoPreference)
+    }
+}
+
+#[cfg(feature = "text-scale-factor")]
+fn get_text_scale_factor(support: &JavaSupport, env: &mut JNIEnv) -> Result<TextScaleFactor> {
+    let factor = support.get_text_scale_factor(env)?;
+    Ok(TextScaleFactor(Some(factor)))
+}
+
+#[cfg(feature = "time-format")]
+fn get_time_format(support: &JavaSupport, env: &mut JNIEnv) -> Result<TimeFormat> {
+    if support.get_time_format_24_hour(env)? {
+        Ok(TimeFormat::TwentyFour)
+    } else {
+        Ok(TimeFormat::Twelve)
+    }
+}
+
+// Material You's dynamic accent color (`android.R.color.system_accent1_500`) was only
+// introduced in Android 12 (API level 31); on older devices the resource doesn't exist,
+// so there's no accent color to report at all rather than merely "no preference".
+#[cfg(feature = "accent-color")]
+const ACCENT_COLOR_MIN_SDK_INT: i32 = 31;
+
+pub(crate) fn supported_interests() -> Interest {
+    try_supported_interests().unwrap_or_default()
+}
+
+fn try_supported_interests() -> Result<Interest> {
+    let vm = java_vm()?;
+    let mut env = vm.attach_current_thread()?;
+    let support = JavaSupport::get()?;
+    #[cfg(feature = "accent-color")]
+    let sdk_int = support.get_sdk_int(&mut env)?;
+
+    let mut interest = Interest::default();
+    #[cfg(feature = "color-scheme")]
+    {
+        interest = interest | Interest::ColorScheme;
+    }
+    #[cfg(feature = "contrast")]
+    {
+        interest = interest | Interest::Contrast;
+    }
+    #[cfg(feature = "reduced-motion")]
+    {
+        interest = interest | Interest::ReducedMotion;
+    }
+    #[cfg(feature = "reduced-transparency")]
+    {
+        interest = interest | Interest::ReducedTransparency;
+    }
+    #[cfg(feature = "accent-color")]
+    if sdk_int >= ACCENT_COLOR_MIN_SDK_INT {
+        interest = interest | Interest::AccentColor;
+    }
+    #[cfg(feature = "text-scale-factor")]
+    {
+        interest = interest | Interest::TextScaleFactor;
+    }
+    #[cfg(feature = "time-format")]
+    {
+        interest = interest | Interest::TimeFormat;
+    }
+
+    Ok(interest)
 }
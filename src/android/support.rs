@@ -51,6 +51,53 @@ impl JavaSupport {
             .expect("method to return a boolean"))
     }
 
+    #[cfg(feature = "text-scale-factor")]
+    pub(crate) fn get_text_scale_factor(&self, env: &mut JNIEnv) -> Result<f64> {
+        Ok(env
+            .call_method(&self.global_ref, "getTextScaleFactor", "()D", &[])?
+            .d()
+            .expect("method to return a double"))
+    }
+
+    #[cfg(feature = "reduced-motion")]
+    pub(crate) fn get_prefers_reduced_motion(&self, env: &mut JNIEnv) -> Result<bool> {
+        Ok(env
+            .call_method(&self.global_ref, "getPrefersReducedMotion", "()Z", &[])?
+            .z()
+            .expect("method to return a boolean"))
+    }
+
+    #[cfg(feature = "accent-color")]
+    pub(crate) fn get_accent_color(&self, env: &mut JNIEnv) -> Result<i32> {
+        Ok(env
+            .call_method(&self.global_ref, "getAccentColor", "()I", &[])?
+            .i()
+            .expect("method to return an int"))
+    }
+
+    #[cfg(feature = "reduced-transparency")]
+    pub(crate) fn get_reduced_transparency(&self, env: &mut JNIEnv) -> Result<bool> {
+        Ok(env
+            .call_method(&self.global_ref, "getReducedTransparency", "()Z", &[])?
+            .z()
+            .expect("method to return a boolean"))
+    }
+
+    #[cfg(feature = "time-format")]
+    pub(crate) fn get_time_format_24_hour(&self, env: &mut JNIEnv) -> Result<bool> {
+        Ok(env
+            .call_method(&self.global_ref, "getTimeFormat24Hour", "()Z", &[])?
+            .z()
+            .expect("method to return a boolean"))
+    }
+
+    pub(crate) fn get_sdk_int(&self, env: &mut JNIEnv) -> Result<i32> {
+        Ok(env
+            .call_method(&self.global_ref, "getSdkInt", "()I", &[])?
+            .i()
+            .expect("method to return an int"))
+    }
+
     pub(crate) fn subscribe(&self, env: &mut JNIEnv) -> Result<()> {
         env.call_method(&self.global_ref, "subscribe", "()V", &[])?;
         Ok(())
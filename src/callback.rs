@@ -1,11 +1,42 @@
 use crate::{async_rt, Interest, Preferences};
 use futures_channel::oneshot;
 use futures_lite::{stream, StreamExt as _};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
 
 pub trait CallbackFn: FnMut(Preferences) + Send + Sync + 'static {}
 
 impl<F> CallbackFn for F where F: FnMut(Preferences) + Send + Sync + 'static {}
 
+/// A future, boxed up so it can be handed to a [`Spawn`] implementation without
+/// that implementation needing to know the concrete future type.
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A pluggable executor for [`Preferences::subscribe_with`] and
+/// [`Preferences::subscribe_debounced_with`].
+///
+/// Implement this to hand a subscription off to an executor you already run
+/// (tokio, async-executor, ...) instead of having mundy spawn a dedicated OS
+/// thread per subscription, which is what [`Preferences::subscribe`] and
+/// [`Preferences::subscribe_debounced`] do by default.
+pub trait Spawn {
+    /// Runs `future` to completion. The future resolves on its own once the
+    /// [`Subscription`] it belongs to is dropped, so implementations don't need
+    /// to do anything special to cancel it early.
+    fn spawn(&self, future: BoxFuture);
+}
+
+/// The [`Spawn`] implementation used by [`Preferences::subscribe`] and
+/// [`Preferences::subscribe_debounced`]: one OS thread per subscription.
+struct ThreadSpawn;
+
+impl Spawn for ThreadSpawn {
+    fn spawn(&self, future: BoxFuture) {
+        async_rt::spawn_future(future);
+    }
+}
+
 /// A subscription for preferences created using [`Preferences::subscribe()`].
 /// Dropping the subscription will cancel it and clean up all associated resources.
 pub struct Subscription(
@@ -22,9 +53,24 @@ impl Preferences {
     /// The provided callback is guaranteed to be called at least once with the initial values
     /// and is subsequently called when preferences are updated.
     ///
+    /// Spawns a dedicated OS thread to drive the subscription. Use
+    /// [`Preferences::subscribe_with`] to run it on an executor you already have
+    /// instead.
+    ///
     #[doc = include_str!("doc/caveats.md")]
-    pub fn subscribe(interest: Interest, mut callback: impl CallbackFn) -> Subscription {
-        // No need to spawn a thread if the interests are empty.
+    pub fn subscribe(interest: Interest, callback: impl CallbackFn) -> Subscription {
+        Self::subscribe_with(interest, &ThreadSpawn, callback)
+    }
+
+    /// Like [`Preferences::subscribe()`], but runs the subscription on `spawn`
+    /// instead of spawning a dedicated OS thread.
+    #[doc = include_str!("doc/caveats.md")]
+    pub fn subscribe_with(
+        interest: Interest,
+        spawn: &impl Spawn,
+        mut callback: impl CallbackFn,
+    ) -> Subscription {
+        // No need to spawn anything if the interests are empty.
         if interest.is_empty() {
             return Subscription(None);
         }
@@ -32,14 +78,57 @@ impl Preferences {
         let mut stream = Self::stream(interest)
             .map(Message::Preferences)
             .race(stream::once_future(receiver).map(|_| Message::Shutdown));
-        async_rt::spawn_future(async move {
+        spawn.spawn(Box::pin(async move {
+            while let Some(message) = stream.next().await {
+                match message {
+                    Message::Preferences(preferences) => callback(preferences),
+                    Message::Shutdown => break,
+                }
+            }
+        }));
+        Subscription(Some(sender))
+    }
+
+    /// Like [`Preferences::subscribe()`], but additionally debounces updates
+    /// as described by [`Preferences::stream_debounced()`].
+    ///
+    /// Spawns a dedicated OS thread to drive the subscription. Use
+    /// [`Preferences::subscribe_debounced_with`] to run it on an executor you
+    /// already have instead.
+    #[doc = include_str!("doc/caveats.md")]
+    pub fn subscribe_debounced(
+        interest: Interest,
+        duration: Duration,
+        callback: impl CallbackFn,
+    ) -> Subscription {
+        Self::subscribe_debounced_with(interest, duration, &ThreadSpawn, callback)
+    }
+
+    /// Like [`Preferences::subscribe_debounced()`], but runs the subscription
+    /// on `spawn` instead of spawning a dedicated OS thread.
+    #[doc = include_str!("doc/caveats.md")]
+    pub fn subscribe_debounced_with(
+        interest: Interest,
+        duration: Duration,
+        spawn: &impl Spawn,
+        mut callback: impl CallbackFn,
+    ) -> Subscription {
+        // No need to spawn anything if the interests are empty.
+        if interest.is_empty() {
+            return Subscription(None);
+        }
+        let (sender, receiver) = oneshot::channel();
+        let mut stream = Self::stream_debounced(interest, duration)
+            .map(Message::Preferences)
+            .race(stream::once_future(receiver).map(|_| Message::Shutdown));
+        spawn.spawn(Box::pin(async move {
             while let Some(message) = stream.next().await {
                 match message {
                     Message::Preferences(preferences) => callback(preferences),
                     Message::Shutdown => break,
                 }
             }
-        });
+        }));
         Subscription(Some(sender))
     }
 }
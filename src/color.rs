@@ -10,6 +10,8 @@ use std::fmt;
 /// let (r, g, b, a) = color.to_u8_array().into();
 /// ```
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Srgba {
     pub red: f64,
     pub green: f64,
@@ -67,4 +69,207 @@ impl Srgba {
     pub fn from_u8_array(color: [u8; 4]) -> Self {
         Self::from_f64_array(color.map(|c| c as f64 / 255.))
     }
+
+    /// Composites this color over `background`, using this color's alpha, and returns
+    /// the resulting opaque color. Needed before doing any luminance/contrast math on a
+    /// translucent color, since e.g. a half-transparent white looks very different
+    /// against a black background than against a white one.
+    pub fn composited_over(self, background: Srgba) -> Srgba {
+        let blend = |fg: f64, bg: f64| fg * self.alpha + bg * (1. - self.alpha);
+        Srgba {
+            red: blend(self.red, background.red),
+            green: blend(self.green, background.green),
+            blue: blend(self.blue, background.blue),
+            alpha: 1.,
+        }
+    }
+
+    /// The WCAG relative luminance of this color, ignoring alpha (composite over a
+    /// background with [`composited_over`](Self::composited_over) first if this color
+    /// isn't already opaque).
+    ///
+    /// See <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>.
+    pub fn relative_luminance(self) -> f64 {
+        // The WCAG spec's own transfer function, distinct from `srgb_eotf`'s more
+        // precise one: it uses a `0.03928` cutoff (vs. `0.04045`), which leaves a tiny
+        // discontinuity in the curve; contrast checkers are expected to match the spec
+        // exactly, so we do too.
+        fn linearize(c: f64) -> f64 {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        0.2126 * linearize(self.red) + 0.7152 * linearize(self.green) + 0.0722 * linearize(self.blue)
+    }
+
+    /// The WCAG contrast ratio between this color and `other`, ignoring alpha (composite
+    /// over a background with [`composited_over`](Self::composited_over) first if either
+    /// color isn't already opaque). Ranges from `1.0` (no contrast) to `21.0` (black on
+    /// white).
+    ///
+    /// See <https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio>.
+    pub fn contrast_ratio(self, other: Srgba) -> f64 {
+        let (l1, l2) = (self.relative_luminance(), other.relative_luminance());
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+pub(crate) const BLACK: Srgba = Srgba {
+    red: 0.,
+    green: 0.,
+    blue: 0.,
+    alpha: 1.,
+};
+pub(crate) const WHITE: Srgba = Srgba {
+    red: 1.,
+    green: 1.,
+    blue: 1.,
+    alpha: 1.,
+};
+
+/// A color in the Display P3 color space, which has a wider gamut than sRGB (most
+/// notably in saturated reds and greens). Each component is in the range `[0, 1]` and
+/// gamma-encoded with the same transfer function as [`Srgba`].
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayP3 {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl fmt::Debug for DisplayP3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayP3")
+            .field("red", &self.red)
+            .field("green", &self.green)
+            .field("blue", &self.blue)
+            .field("alpha", &self.alpha)
+            .finish()
+    }
+}
+
+impl DisplayP3 {
+    pub fn from_f64_array(color: [f64; 4]) -> Self {
+        Self {
+            red: color[0],
+            green: color[1],
+            blue: color[2],
+            alpha: color[3],
+        }
+    }
+
+    pub fn to_f64_array(self) -> [f64; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Gamut-maps this color down into sRGB, clamping any component that falls outside
+    /// of it. Display P3 shares sRGB's transfer function and white point, so the
+    /// conversion is: decode P3's gamma, apply the fixed linear-light P3-to-sRGB matrix,
+    /// then re-encode sRGB's gamma.
+    ///
+    /// Matrix taken from the reference conversion code in
+    /// <https://www.w3.org/TR/css-color-4/#color-conversion-code>.
+    pub fn to_srgba(self) -> Srgba {
+        const P3_TO_SRGB: [[f64; 3]; 3] = [
+            [1.2249401762, -0.2249401762, 0.0000000000],
+            [-0.0420569547, 1.0420569547, 0.0000000000],
+            [-0.0196375546, -0.0786360455, 1.0982736021],
+        ];
+        let linear = self.to_linear();
+        let [red, green, blue] = P3_TO_SRGB.map(|row| {
+            srgb_oetf(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2])
+        });
+        Srgba::from_f64_array([
+            red.clamp(0., 1.),
+            green.clamp(0., 1.),
+            blue.clamp(0., 1.),
+            self.alpha,
+        ])
+    }
+
+    /// Returns this color's red/green/blue components decoded into linear light
+    /// (gamma removed), still in the Display P3 primaries.
+    pub fn to_linear(self) -> [f64; 3] {
+        [srgb_eotf(self.red), srgb_eotf(self.green), srgb_eotf(self.blue)]
+    }
+}
+
+// The sRGB electro-optical transfer function (gamma decode), i.e. nonlinear -> linear.
+// Display P3 reuses the exact same transfer function.
+fn srgb_eotf(c: f64) -> f64 {
+    if c.abs() <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// The inverse of [`srgb_eotf`] (gamma encode), i.e. linear -> nonlinear.
+fn srgb_oetf(c: f64) -> f64 {
+    if c.abs() <= 0.0031308 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * c.abs().powf(1. / 2.4) - 0.055)
+    }
+}
+
+/// A color that may be expressed in a gamut wider than sRGB.
+///
+/// Preferences like [`AccentColor`](crate::AccentColor) can be set by the user on a
+/// wide-gamut display, and clamping them down to sRGB immediately loses information
+/// (most visibly on vivid accent colors picked on a Display P3 screen). This type keeps
+/// the original wide-gamut components around; call [`to_srgba`](Self::to_srgba) to get
+/// a gamut-mapped color for sRGB rendering pipelines.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WideGamutColor {
+    Srgb(Srgba),
+    DisplayP3(DisplayP3),
+}
+
+impl WideGamutColor {
+    /// Gamut-maps this color down into sRGB, clamping any out-of-gamut components.
+    pub fn to_srgba(self) -> Srgba {
+        match self {
+            WideGamutColor::Srgb(color) => color,
+            WideGamutColor::DisplayP3(color) => color.to_srgba(),
+        }
+    }
+
+    /// Returns this color's red/green/blue components decoded into linear light
+    /// (gamma removed), in whichever gamut this color was expressed in.
+    pub fn to_linear(self) -> [f64; 3] {
+        match self {
+            WideGamutColor::Srgb(color) => {
+                [srgb_eotf(color.red), srgb_eotf(color.green), srgb_eotf(color.blue)]
+            }
+            WideGamutColor::DisplayP3(color) => color.to_linear(),
+        }
+    }
+
+    pub fn alpha(self) -> f64 {
+        match self {
+            WideGamutColor::Srgb(color) => color.alpha,
+            WideGamutColor::DisplayP3(color) => color.alpha,
+        }
+    }
+}
+
+impl From<Srgba> for WideGamutColor {
+    fn from(color: Srgba) -> Self {
+        WideGamutColor::Srgb(color)
+    }
+}
+
+impl From<DisplayP3> for WideGamutColor {
+    fn from(color: DisplayP3) -> Self {
+        WideGamutColor::DisplayP3(color)
+    }
 }
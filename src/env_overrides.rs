@@ -0,0 +1,137 @@
+//! Lets preferences be forced via `MUNDY_*` environment variables, which is
+//! useful for deterministic testing, CI, and headless runs where there's no
+//! portal/`window` to read the real system preference from.
+
+#[cfg(feature = "accent-color")]
+use crate::{AccentColor, Srgba};
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+#[cfg(feature = "reduced-motion")]
+use crate::ReducedMotion;
+use crate::{Interest, Preferences};
+use std::env;
+
+/// Preference values forced via `MUNDY_*` environment variables, along with
+/// an [`Interest`] mask of which preferences were actually overridden.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Overrides {
+    preferences: Preferences,
+    interest: Interest,
+}
+
+impl Overrides {
+    pub(crate) fn read() -> Self {
+        let mut preferences = Preferences::default();
+        #[allow(unused_mut)]
+        let mut interest = Interest::default();
+
+        #[cfg(feature = "color-scheme")]
+        if let Some(value) = parse_env("MUNDY_COLOR_SCHEME", parse_color_scheme) {
+            preferences.color_scheme = value;
+            interest = interest | Interest::ColorScheme;
+        }
+
+        #[cfg(feature = "contrast")]
+        if let Some(value) = parse_env("MUNDY_CONTRAST", parse_contrast) {
+            preferences.contrast = value;
+            interest = interest | Interest::Contrast;
+        }
+
+        #[cfg(feature = "reduced-motion")]
+        if let Some(value) = parse_env("MUNDY_REDUCED_MOTION", parse_reduced_motion) {
+            preferences.reduced_motion = value;
+            interest = interest | Interest::ReducedMotion;
+        }
+
+        #[cfg(feature = "accent-color")]
+        if let Some(value) = parse_env("MUNDY_ACCENT_COLOR", parse_accent_color) {
+            preferences.accent_color = value;
+            interest = interest | Interest::AccentColor;
+        }
+
+        Self {
+            preferences,
+            interest,
+        }
+    }
+
+    /// The subset of preferences that were overridden.
+    pub(crate) fn interest(&self) -> Interest {
+        self.interest
+    }
+
+    /// Overwrites every field in `preferences` that was overridden with its
+    /// forced value, leaving every other field (including ones live-read for
+    /// an `interest` this `Overrides` doesn't cover) untouched.
+    pub(crate) fn apply(&self, mut preferences: Preferences) -> Preferences {
+        #[cfg(feature = "color-scheme")]
+        if self.interest.is(Interest::ColorScheme) {
+            preferences.color_scheme = self.preferences.color_scheme;
+        }
+
+        #[cfg(feature = "contrast")]
+        if self.interest.is(Interest::Contrast) {
+            preferences.contrast = self.preferences.contrast;
+        }
+
+        #[cfg(feature = "reduced-motion")]
+        if self.interest.is(Interest::ReducedMotion) {
+            preferences.reduced_motion = self.preferences.reduced_motion;
+        }
+
+        #[cfg(feature = "accent-color")]
+        if self.interest.is(Interest::AccentColor) {
+            preferences.accent_color = self.preferences.accent_color;
+        }
+
+        preferences
+    }
+}
+
+#[allow(unused)]
+fn parse_env<T>(var: &str, parse: impl FnOnce(&str) -> Option<T>) -> Option<T> {
+    parse(&env::var(var).ok()?)
+}
+
+#[cfg(feature = "color-scheme")]
+fn parse_color_scheme(value: &str) -> Option<ColorScheme> {
+    match value {
+        "dark" => Some(ColorScheme::Dark),
+        "light" => Some(ColorScheme::Light),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "contrast")]
+fn parse_contrast(value: &str) -> Option<Contrast> {
+    match value {
+        "more" => Some(Contrast::More),
+        "less" => Some(Contrast::Less),
+        "custom" => Some(Contrast::Custom),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "reduced-motion")]
+fn parse_reduced_motion(value: &str) -> Option<ReducedMotion> {
+    match value {
+        "reduce" => Some(ReducedMotion::Reduce),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "accent-color")]
+fn parse_accent_color(value: &str) -> Option<AccentColor> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let red = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let green = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let blue = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(AccentColor(Some(
+        Srgba::from_u8_array([red, green, blue, 255]).into(),
+    )))
+}
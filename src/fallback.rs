@@ -18,3 +18,23 @@ pub(crate) fn once_blocking(
 ) -> Option<AvailablePreferences> {
     Some(AvailablePreferences::default())
 }
+
+pub(crate) fn supported_interests() -> Interest {
+    Interest::default()
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn read_raw(
+    _namespace: &'static str,
+    _key: &'static str,
+) -> Option<zbus::zvariant::OwnedValue> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn watch_raw(
+    _namespace: &'static str,
+    _key: &'static str,
+) -> impl futures_lite::Stream<Item = zbus::zvariant::OwnedValue> {
+    futures_lite::stream::empty()
+}
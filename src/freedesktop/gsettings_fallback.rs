@@ -0,0 +1,163 @@
+//! Fallback backend used when `xdg-desktop-portal` isn't reachable at all (older
+//! or minimal desktops without a running portal). Shells out to `gsettings` to
+//! read and watch the small subset of settings mundy can get to this way, rather
+//! than reporting every preference as unavailable.
+//!
+//! Only [`ColorScheme`] and [`Contrast`] are covered, both read from the
+//! `org.gnome.desktop.interface` schema; every other preference mundy models
+//! (accent color, double-click interval, ...) is left at its default by this
+//! backend.
+
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+use crate::stream_utils::Scan;
+use crate::{AvailablePreferences, Interest};
+use async_process::{Child, Command, Stdio};
+use futures_lite::io::{BufReader, Lines};
+use futures_lite::{stream, AsyncBufReadExt as _, Stream, StreamExt as _};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+const SCHEMA: &str = "org.gnome.desktop.interface";
+#[cfg(feature = "color-scheme")]
+const COLOR_SCHEME_KEY: &str = "color-scheme";
+#[cfg(feature = "contrast")]
+const GTK_THEME_KEY: &str = "gtk-theme";
+
+pub(crate) fn stream(interest: Interest) -> impl Stream<Item = AvailablePreferences> {
+    stream::once_future(initial(interest))
+        .flat_map(move |preferences| stream::once(preferences).chain(changes(interest, preferences)))
+}
+
+async fn initial(interest: Interest) -> AvailablePreferences {
+    let mut preferences = AvailablePreferences::default();
+    #[cfg(feature = "color-scheme")]
+    if interest.is(Interest::ColorScheme) {
+        preferences.color_scheme = get(COLOR_SCHEME_KEY)
+            .await
+            .map(|value| parse_color_scheme(&value))
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "contrast")]
+    if interest.is(Interest::Contrast) {
+        preferences.contrast = get(GTK_THEME_KEY)
+            .await
+            .map(|value| parse_contrast(&value))
+            .unwrap_or_default();
+    }
+    preferences
+}
+
+async fn get(key: &str) -> Option<String> {
+    let output = Command::new("gsettings")
+        .args(["get", SCHEMA, key])
+        .output()
+        .await
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| unquote(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn changes(interest: Interest, preferences: AvailablePreferences) -> impl Stream<Item = AvailablePreferences> {
+    let lines = match monitor() {
+        Some(monitor) => monitor.boxed(),
+        None => stream::empty().boxed(),
+    };
+    Scan::new(lines, preferences, move |mut preferences, line| async move {
+        apply_line(interest, &mut preferences, &line);
+        Some((preferences, preferences))
+    })
+}
+
+fn monitor() -> Option<Monitor> {
+    let mut child = Command::new("gsettings")
+        .args(["monitor", SCHEMA])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let stdout = child.stdout.take()?;
+    Some(Monitor {
+        child,
+        lines: BufReader::new(stdout).lines(),
+    })
+}
+
+pin_project! {
+    /// Wraps the `gsettings monitor` child process together with a line stream
+    /// over its stdout. Killing the child on drop is the cleanup invariant this
+    /// type exists to guarantee: once nobody's polling us anymore, we don't want
+    /// an orphaned `gsettings` process lingering around. Output the child still
+    /// has buffered at that point is not read.
+    struct Monitor {
+        child: Child,
+        #[pin]
+        lines: Lines<BufReader<async_process::ChildStdout>>,
+    }
+}
+
+impl Stream for Monitor {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project()
+            .lines
+            .poll_next(cx)
+            .map(|line| line.and_then(Result::ok))
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        _ = self.child.kill();
+    }
+}
+
+fn apply_line(interest: Interest, preferences: &mut AvailablePreferences, line: &str) {
+    let Some((key, value)) = line.split_once(':') else {
+        return;
+    };
+    let value = unquote(value);
+    match key.trim() {
+        #[cfg(feature = "color-scheme")]
+        COLOR_SCHEME_KEY if interest.is(Interest::ColorScheme) => {
+            preferences.color_scheme = parse_color_scheme(&value);
+        }
+        #[cfg(feature = "contrast")]
+        GTK_THEME_KEY if interest.is(Interest::Contrast) => {
+            preferences.contrast = parse_contrast(&value);
+        }
+        _ => {}
+    }
+}
+
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('\'').to_owned()
+}
+
+#[cfg(feature = "color-scheme")]
+fn parse_color_scheme(value: &str) -> ColorScheme {
+    match value {
+        "prefer-dark" => ColorScheme::Dark,
+        "prefer-light" => ColorScheme::Light,
+        _ => ColorScheme::NoPreference,
+    }
+}
+
+// There's no dedicated high-contrast key over `gsettings`; GNOME's high-contrast
+// mode swaps in one of the `HighContrast*` GTK themes, so we treat that theme
+// name as the signal instead, same as the accessibility settings panel does.
+#[cfg(feature = "contrast")]
+fn parse_contrast(gtk_theme: &str) -> Contrast {
+    if gtk_theme.contains("HighContrast") {
+        Contrast::More
+    } else {
+        Contrast::NoPreference
+    }
+}
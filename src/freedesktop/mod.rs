@@ -1,19 +1,36 @@
-#[cfg(feature = "color-scheme")]
+#[cfg(any(feature = "color-scheme", feature = "system-colors"))]
 use crate::ColorScheme;
 #[cfg(feature = "contrast")]
 use crate::Contrast;
 #[cfg(feature = "double-click-interval")]
 use crate::DoubleClickInterval;
+#[cfg(feature = "caret-blink-interval")]
+use crate::CaretBlinkInterval;
 #[cfg(feature = "reduced-motion")]
 use crate::ReducedMotion;
+#[cfg(feature = "system-colors")]
+use crate::SystemColors;
+#[cfg(feature = "system-palette")]
+use crate::SystemPalette;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
+#[cfg(any(
+    feature = "accent-color",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
+use crate::Srgba;
 #[cfg(feature = "accent-color")]
-use crate::{AccentColor, Srgba};
+use crate::AccentColor;
+#[cfg(feature = "font-rendering")]
+use crate::{Antialiasing, FontRendering, Hinting, SubpixelOrder};
 
 use crate::async_rt::block_on;
 use crate::stream_utils::{Left, Right, Scan};
 use crate::{AvailablePreferences, Interest};
 use cfg_if::cfg_if;
 use futures_lite::{stream, FutureExt as _, Stream, StreamExt as _};
+use std::collections::HashMap;
 use std::time::Duration;
 use zbus::{
     proxy::SignalStream,
@@ -21,6 +38,8 @@ use zbus::{
     Connection, Message, Proxy,
 };
 
+mod gsettings_fallback;
+
 #[cfg(feature = "log")]
 fn log_dbus_connection_error(err: &zbus::Error) {
     log::warn!("failed to connect to dbus: {err:?}");
@@ -46,13 +65,22 @@ fn log_message_error(err: &zbus::Error) {
 fn log_message_error(_err: &zbus::Error) {}
 
 const APPEARANCE: &str = "org.freedesktop.appearance";
-#[cfg(feature = "reduced-motion")]
+#[cfg(any(
+    feature = "reduced-motion",
+    feature = "time-format",
+    feature = "caret-blink-interval",
+    feature = "font-rendering"
+))]
 const GNOME_INTERFACE: &str = "org.gnome.desktop.interface";
 #[cfg(feature = "double-click-interval")]
 const GNOME_PERIPHERALS_MOUSE: &str = "org.gnome.desktop.peripherals.mouse";
 #[cfg(feature = "double-click-interval")]
 const DOUBLE_CLICK: &str = "double-click";
-#[cfg(feature = "color-scheme")]
+#[cfg(feature = "caret-blink-interval")]
+const CURSOR_BLINK: &str = "cursor-blink";
+#[cfg(feature = "caret-blink-interval")]
+const CURSOR_BLINK_TIME: &str = "cursor-blink-time";
+#[cfg(any(feature = "color-scheme", feature = "system-colors"))]
 const COLOR_SCHEME: &str = "color-scheme";
 #[cfg(feature = "contrast")]
 const CONTRAST: &str = "contrast";
@@ -60,6 +88,14 @@ const CONTRAST: &str = "contrast";
 const ACCENT_COLOR: &str = "accent-color";
 #[cfg(feature = "reduced-motion")]
 const ENABLE_ANIMATIONS: &str = "enable-animations";
+#[cfg(feature = "time-format")]
+const CLOCK_FORMAT: &str = "clock-format";
+#[cfg(feature = "font-rendering")]
+const FONT_ANTIALIASING: &str = "font-antialiasing";
+#[cfg(feature = "font-rendering")]
+const FONT_HINTING: &str = "font-hinting";
+#[cfg(feature = "font-rendering")]
+const FONT_RGBA_ORDER: &str = "font-rgba-order";
 
 pub(crate) type PreferencesStream = stream::Boxed<AvailablePreferences>;
 
@@ -75,6 +111,13 @@ pub(crate) fn once_blocking(interest: Interest, timeout: Duration) -> Option<Ava
     block_on(stream(interest).next().or(timer(timeout)))
 }
 
+// The XDG Settings portal and its GNOME-specific extensions are always queried over
+// D-Bus, with no upfront capability negotiation, so there's nothing here that can
+// fail ahead of time the way a missing WinRT/JNI object can on Windows or Android.
+pub(crate) fn supported_interests() -> Interest {
+    Interest::All
+}
+
 cfg_if! {
     if #[cfg(feature = "tokio")] {
         async fn timer<T>(duration: Duration) -> Option<T> {
@@ -90,10 +133,17 @@ cfg_if! {
 }
 
 fn preferences_stream(interest: Interest) -> impl Stream<Item = AvailablePreferences> {
-    stream::once_future(subscribe(interest)).flat_map(move |(preferences, stream)| {
-        let initial_value = stream::once(preferences);
-        let stream = stream.map(Left).unwrap_or_else(|| Right(stream::empty()));
-        initial_value.chain(changes(interest, preferences, stream))
+    stream::once_future(subscribe(interest)).flat_map(move |subscribed| match subscribed {
+        Subscribed::Portal(preferences, stream) => {
+            let initial_value = stream::once(preferences);
+            let stream = stream.map(Left).unwrap_or_else(|| Right(stream::empty()));
+            Left(initial_value.chain(changes(interest, preferences, stream)))
+        }
+        // The portal itself is unreachable (no `xdg-desktop-portal` running), rather
+        // than just one namespace/key within it being missing, so there's nothing
+        // left to read over D-Bus. Fall back to `gsettings` for the handful of
+        // settings it can still get us.
+        Subscribed::Fallback => Right(gsettings_fallback::stream(interest)),
     })
 }
 
@@ -114,22 +164,30 @@ fn changes(
     )
 }
 
-async fn subscribe(interest: Interest) -> (AvailablePreferences, Option<SignalStream<'static>>) {
+enum Subscribed {
+    Portal(AvailablePreferences, Option<SignalStream<'static>>),
+    /// The portal is unreachable; fall back to `gsettings` instead.
+    Fallback,
+}
+
+async fn subscribe(interest: Interest) -> Subscribed {
     match connect().await {
         Ok(proxy) => {
             let stream = setting_changed(&proxy, interest)
                 .await
                 .inspect_err(log_dbus_connection_error)
                 .ok();
-            let preferences = initial_preferences(&proxy, interest)
-                .await
-                .inspect_err(log_initial_settings_retrieval_error)
-                .unwrap_or_default();
-            (preferences, stream)
+            match initial_preferences(&proxy, interest).await {
+                Ok(preferences) => Subscribed::Portal(preferences, stream),
+                Err(err) => {
+                    log_initial_settings_retrieval_error(&err);
+                    Subscribed::Fallback
+                }
+            }
         }
         Err(err) => {
             log_dbus_connection_error(&err);
-            Default::default()
+            Subscribed::Fallback
         }
     }
 }
@@ -147,9 +205,25 @@ async fn apply_message(
     let body = message.body();
     let (namespace, key, value): (&str, &str, Value) = body.deserialize()?;
     match (namespace, key) {
-        #[cfg(feature = "color-scheme")]
-        (APPEARANCE, COLOR_SCHEME) if interest.is(Interest::ColorScheme) => {
-            preferences.color_scheme = parse_color_scheme(value);
+        #[cfg(any(
+            feature = "color-scheme",
+            feature = "system-colors",
+            feature = "system-palette"
+        ))]
+        (APPEARANCE, COLOR_SCHEME) if wants_color_scheme_refresh(interest) => {
+            let color_scheme = parse_color_scheme(value);
+            #[cfg(feature = "color-scheme")]
+            if interest.is(Interest::ColorScheme) {
+                preferences.color_scheme = color_scheme;
+            }
+            #[cfg(feature = "system-colors")]
+            if interest.is(Interest::SystemColors) {
+                preferences.system_colors = derive_system_colors(color_scheme);
+            }
+            #[cfg(feature = "system-palette")]
+            if interest.is(Interest::SystemPalette) {
+                preferences.system_palette = derive_system_palette(color_scheme);
+            }
         }
         #[cfg(feature = "contrast")]
         (APPEARANCE, CONTRAST) if interest.is(Interest::Contrast) => {
@@ -167,6 +241,31 @@ async fn apply_message(
         (GNOME_PERIPHERALS_MOUSE, DOUBLE_CLICK) if interest.is(Interest::DoubleClickInterval) => {
             preferences.double_click_interval = parse_double_click(value);
         }
+        #[cfg(feature = "time-format")]
+        (GNOME_INTERFACE, CLOCK_FORMAT) if interest.is(Interest::TimeFormat) => {
+            preferences.time_format = parse_clock_format(value);
+        }
+        #[cfg(feature = "caret-blink-interval")]
+        (GNOME_INTERFACE, CURSOR_BLINK) if interest.is(Interest::CaretBlinkInterval) => {
+            preferences.caret_blink_interval =
+                parse_cursor_blink(value, preferences.caret_blink_interval);
+        }
+        #[cfg(feature = "caret-blink-interval")]
+        (GNOME_INTERFACE, CURSOR_BLINK_TIME) if interest.is(Interest::CaretBlinkInterval) => {
+            preferences.caret_blink_interval = parse_cursor_blink_time(value);
+        }
+        #[cfg(feature = "font-rendering")]
+        (GNOME_INTERFACE, FONT_ANTIALIASING) if interest.is(Interest::FontRendering) => {
+            preferences.font_rendering.antialiasing = parse_font_antialiasing(value);
+        }
+        #[cfg(feature = "font-rendering")]
+        (GNOME_INTERFACE, FONT_HINTING) if interest.is(Interest::FontRendering) => {
+            preferences.font_rendering.hinting = parse_font_hinting(value);
+        }
+        #[cfg(feature = "font-rendering")]
+        (GNOME_INTERFACE, FONT_RGBA_ORDER) if interest.is(Interest::FontRendering) => {
+            preferences.font_rendering.subpixel_order = parse_font_rgba_order(value);
+        }
         _ => {}
     }
     Ok(())
@@ -175,6 +274,106 @@ async fn apply_message(
 async fn initial_preferences(
     proxy: &Proxy<'_>,
     interest: Interest,
+) -> zbus::Result<AvailablePreferences> {
+    // `ReadAll` lets us fetch every namespace we care about in a single round-trip
+    // instead of one `Read` call per preference. Not every portal implementation
+    // exposes it, though, so fall back to the sequential `Read` loop if it fails.
+    match initial_preferences_read_all(proxy, interest).await {
+        Ok(preferences) => Ok(preferences),
+        Err(err) => {
+            log_read_all_error(&err);
+            initial_preferences_sequential(proxy, interest).await
+        }
+    }
+}
+
+async fn initial_preferences_read_all(
+    proxy: &Proxy<'_>,
+    interest: Interest,
+) -> zbus::Result<AvailablePreferences> {
+    let namespaces = read_all_namespaces(interest);
+    let all: HashMap<String, HashMap<String, OwnedValue>> =
+        proxy.call("ReadAll", &(namespaces,)).await?;
+
+    let mut preferences = AvailablePreferences::default();
+    #[cfg(feature = "color-scheme")]
+    if interest.is(Interest::ColorScheme) {
+        preferences.color_scheme = read_all_setting(&all, APPEARANCE, COLOR_SCHEME)
+            .map(parse_color_scheme)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "contrast")]
+    if interest.is(Interest::Contrast) {
+        preferences.contrast = read_all_setting(&all, APPEARANCE, CONTRAST)
+            .map(parse_contrast)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "reduced-motion")]
+    if interest.is(Interest::ReducedMotion) {
+        preferences.reduced_motion = read_all_setting(&all, GNOME_INTERFACE, ENABLE_ANIMATIONS)
+            .map(parse_enable_animation)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "accent-color")]
+    if interest.is(Interest::AccentColor) {
+        preferences.accent_color = read_all_setting(&all, APPEARANCE, ACCENT_COLOR)
+            .map(parse_accent_color)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "double-click-interval")]
+    if interest.is(Interest::DoubleClickInterval) {
+        preferences.double_click_interval =
+            read_all_setting(&all, GNOME_PERIPHERALS_MOUSE, DOUBLE_CLICK)
+                .map(parse_double_click)
+                .unwrap_or_default();
+    }
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        preferences.time_format = read_all_setting(&all, GNOME_INTERFACE, CLOCK_FORMAT)
+            .map(parse_clock_format)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "system-colors")]
+    if interest.is(Interest::SystemColors) {
+        let color_scheme = read_all_setting(&all, APPEARANCE, COLOR_SCHEME)
+            .map(parse_color_scheme)
+            .unwrap_or_default();
+        preferences.system_colors = derive_system_colors(color_scheme);
+    }
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        let color_scheme = read_all_setting(&all, APPEARANCE, COLOR_SCHEME)
+            .map(parse_color_scheme)
+            .unwrap_or_default();
+        preferences.system_palette = derive_system_palette(color_scheme);
+    }
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        let enabled = read_all_setting(&all, GNOME_INTERFACE, CURSOR_BLINK).and_then(parse_bool);
+        let millis =
+            read_all_setting(&all, GNOME_INTERFACE, CURSOR_BLINK_TIME).and_then(parse_i32);
+        preferences.caret_blink_interval = combine_cursor_blink(enabled, millis);
+    }
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        preferences.font_rendering = FontRendering {
+            antialiasing: read_all_setting(&all, GNOME_INTERFACE, FONT_ANTIALIASING)
+                .map(parse_font_antialiasing)
+                .unwrap_or_default(),
+            hinting: read_all_setting(&all, GNOME_INTERFACE, FONT_HINTING)
+                .map(parse_font_hinting)
+                .unwrap_or_default(),
+            subpixel_order: read_all_setting(&all, GNOME_INTERFACE, FONT_RGBA_ORDER)
+                .map(parse_font_rgba_order)
+                .unwrap_or_default(),
+        };
+    }
+    Ok(preferences)
+}
+
+async fn initial_preferences_sequential(
+    proxy: &Proxy<'_>,
+    interest: Interest,
 ) -> zbus::Result<AvailablePreferences> {
     let mut preferences = AvailablePreferences::default();
     #[cfg(feature = "color-scheme")]
@@ -214,9 +413,127 @@ async fn initial_preferences(
                 .map(parse_double_click)
                 .unwrap_or_default();
     }
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        preferences.time_format = read_setting(proxy, GNOME_INTERFACE, CLOCK_FORMAT)
+            .await
+            .map(parse_clock_format)
+            .unwrap_or_default();
+    }
+    #[cfg(feature = "system-colors")]
+    if interest.is(Interest::SystemColors) {
+        let color_scheme = read_setting(proxy, APPEARANCE, COLOR_SCHEME)
+            .await
+            .map(parse_color_scheme)
+            .unwrap_or_default();
+        preferences.system_colors = derive_system_colors(color_scheme);
+    }
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        let color_scheme = read_setting(proxy, APPEARANCE, COLOR_SCHEME)
+            .await
+            .map(parse_color_scheme)
+            .unwrap_or_default();
+        preferences.system_palette = derive_system_palette(color_scheme);
+    }
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        let enabled = read_setting(proxy, GNOME_INTERFACE, CURSOR_BLINK)
+            .await
+            .and_then(parse_bool);
+        let millis = read_setting(proxy, GNOME_INTERFACE, CURSOR_BLINK_TIME)
+            .await
+            .and_then(parse_i32);
+        preferences.caret_blink_interval = combine_cursor_blink(enabled, millis);
+    }
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        preferences.font_rendering = FontRendering {
+            antialiasing: read_setting(proxy, GNOME_INTERFACE, FONT_ANTIALIASING)
+                .await
+                .map(parse_font_antialiasing)
+                .unwrap_or_default(),
+            hinting: read_setting(proxy, GNOME_INTERFACE, FONT_HINTING)
+                .await
+                .map(parse_font_hinting)
+                .unwrap_or_default(),
+            subpixel_order: read_setting(proxy, GNOME_INTERFACE, FONT_RGBA_ORDER)
+                .await
+                .map(parse_font_rgba_order)
+                .unwrap_or_default(),
+        };
+    }
     Ok(preferences)
 }
 
+// The set of `ReadAll` namespaces implied by `interest`. `APPEARANCE` is always
+// included since it's cheap and backs most of the preferences we care about.
+fn read_all_namespaces(
+    #[cfg_attr(
+        not(any(
+            feature = "reduced-motion",
+            feature = "double-click-interval",
+            feature = "time-format",
+            feature = "caret-blink-interval",
+            feature = "font-rendering"
+        )),
+        expect(unused_variables)
+    )]
+    interest: Interest,
+) -> Vec<&'static str> {
+    let mut namespaces = vec![APPEARANCE];
+    #[cfg(feature = "reduced-motion")]
+    if interest.is(Interest::ReducedMotion) {
+        namespaces.push(GNOME_INTERFACE);
+    }
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        namespaces.push(GNOME_INTERFACE);
+    }
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        namespaces.push(GNOME_INTERFACE);
+    }
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        namespaces.push(GNOME_INTERFACE);
+    }
+    #[cfg(feature = "double-click-interval")]
+    if interest.is(Interest::DoubleClickInterval) {
+        namespaces.push(GNOME_PERIPHERALS_MOUSE);
+    }
+    namespaces
+}
+
+#[cfg(any(
+    feature = "color-scheme",
+    feature = "contrast",
+    feature = "reduced-motion",
+    feature = "accent-color",
+    feature = "double-click-interval",
+    feature = "time-format",
+    feature = "system-colors",
+    feature = "system-palette",
+    feature = "caret-blink-interval",
+    feature = "font-rendering"
+))]
+fn read_all_setting(
+    all: &HashMap<String, HashMap<String, OwnedValue>>,
+    namespace: &str,
+    key: &str,
+) -> Option<Value<'static>> {
+    let value = all.get(namespace)?.get(key)?.clone();
+    Some(flatten_value(Value::from(value)))
+}
+
+#[cfg(feature = "log")]
+fn log_read_all_error(err: &zbus::Error) {
+    log::debug!("`ReadAll` unavailable, falling back to per-key `Read`: {err:?}");
+}
+
+#[cfg(not(feature = "log"))]
+fn log_read_all_error(_err: &zbus::Error) {}
+
 async fn settings_proxy<'a>(connection: &Connection) -> zbus::Result<Proxy<'a>> {
     Proxy::new(
         connection,
@@ -256,18 +573,174 @@ async fn setting_changed(
         .await
 }
 
+/// Escape hatch used by [`crate::platform::linux::read_raw`] to read settings
+/// that mundy doesn't model as a typed preference.
+pub(crate) fn read_raw(namespace: &'static str, key: &'static str) -> Option<OwnedValue> {
+    block_on(read_raw_async(namespace, key))
+}
+
+async fn read_raw_async(namespace: &str, key: &str) -> Option<OwnedValue> {
+    let proxy = connect().await.inspect_err(log_dbus_connection_error).ok()?;
+    let value = read_setting(&proxy, namespace, key).await?;
+    value.try_to_owned().ok()
+}
+
+/// Escape hatch used by [`crate::platform::linux::watch_raw`] to watch settings
+/// that mundy doesn't model as a typed preference.
+pub(crate) fn watch_raw(
+    namespace: &'static str,
+    key: &'static str,
+) -> impl Stream<Item = OwnedValue> {
+    stream::once_future(watch_raw_async(namespace, key)).flat_map(|stream| stream)
+}
+
+async fn watch_raw_async(namespace: &'static str, key: &'static str) -> stream::Boxed<OwnedValue> {
+    let Ok(proxy) = connect().await.inspect_err(log_dbus_connection_error) else {
+        return stream::empty().boxed();
+    };
+    let Ok(changed) = proxy
+        .receive_signal_with_args("SettingChanged", &[(0, namespace)])
+        .await
+        .inspect_err(log_dbus_connection_error)
+    else {
+        return stream::empty().boxed();
+    };
+    changed
+        .filter_map(move |message| {
+            let body = message.body();
+            let (ns, k, value): (&str, &str, Value) = body.deserialize().ok()?;
+            if ns != namespace || k != key {
+                return None;
+            }
+            flatten_value(value).try_to_owned().ok()
+        })
+        .boxed()
+}
+
 fn signal_filter(
-    #[cfg_attr(not(feature = "_gnome_only"), expect(unused_variables))] interest: Interest,
+    #[cfg_attr(
+        not(any(
+            feature = "_gnome_only",
+            feature = "reduced-motion",
+            feature = "caret-blink-interval",
+            feature = "font-rendering"
+        )),
+        expect(unused_variables)
+    )]
+    interest: Interest,
 ) -> &'static [(u8, &'static str)] {
     #[cfg(feature = "_gnome_only")]
     if interest.is(Interest::GnomeOnly) {
         return &[];
     }
+    // `enable-animations` lives under `org.gnome.desktop.interface`, not
+    // `org.freedesktop.appearance`, so the filter below would otherwise drop its
+    // `SettingChanged` signal entirely and reduced-motion updates would only ever
+    // be captured on the initial read.
+    #[cfg(feature = "reduced-motion")]
+    if interest.is(Interest::ReducedMotion) {
+        return &[];
+    }
+    // Same reasoning as `reduced-motion` above: `cursor-blink`/`cursor-blink-time`
+    // also live under `org.gnome.desktop.interface`.
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        return &[];
+    }
+    // Same reasoning again: `font-antialiasing`/`font-hinting`/`font-rgba-order`
+    // also live under `org.gnome.desktop.interface`.
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        return &[];
+    }
     &[(0, APPEARANCE)]
 }
 
+#[cfg(any(
+    feature = "color-scheme",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
+fn wants_color_scheme_refresh(interest: Interest) -> bool {
+    #[cfg(feature = "color-scheme")]
+    if interest.is(Interest::ColorScheme) {
+        return true;
+    }
+    #[cfg(feature = "system-colors")]
+    if interest.is(Interest::SystemColors) {
+        return true;
+    }
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        return true;
+    }
+    false
+}
+
+// GTK/libadwaita compute their named colors (`@theme_fg_color`, `@theme_bg_color`, …)
+// in CSS rather than exposing them through a portal setting, so there's no D-Bus key
+// to read them from directly. We approximate libadwaita's default light/dark palette
+// instead of leaving every field unset.
+#[cfg(feature = "system-colors")]
+fn derive_system_colors(color_scheme: ColorScheme) -> SystemColors {
+    if color_scheme.is_dark() {
+        SystemColors {
+            label: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            control_background: Some(Srgba::from_u8_array([36, 36, 36, 255])),
+            selected_content_background: Some(Srgba::from_u8_array([53, 132, 228, 255])),
+            separator: Some(Srgba::from_u8_array([255, 255, 255, 25])),
+            placeholder_text: Some(Srgba::from_u8_array([255, 255, 255, 128])),
+        }
+    } else {
+        SystemColors {
+            label: Some(Srgba::from_u8_array([0, 0, 0, 255])),
+            control_background: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            selected_content_background: Some(Srgba::from_u8_array([53, 132, 228, 255])),
+            separator: Some(Srgba::from_u8_array([0, 0, 0, 25])),
+            placeholder_text: Some(Srgba::from_u8_array([0, 0, 0, 128])),
+        }
+    }
+}
+
+// Same rationale as `derive_system_colors`: there's no portal key for these, so we
+// approximate libadwaita's default palette. GTK doesn't distinguish visited from
+// unvisited link text or give input fields dedicated colors, so the `visited_text`,
+// `field` and `field_text` slots are left unset.
+#[cfg(feature = "system-palette")]
+fn derive_system_palette(color_scheme: ColorScheme) -> SystemPalette {
+    if color_scheme.is_dark() {
+        SystemPalette {
+            canvas: Some(Srgba::from_u8_array([36, 36, 36, 255])),
+            canvas_text: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            link_text: Some(Srgba::from_u8_array([98, 160, 234, 255])),
+            visited_text: None,
+            highlight: Some(Srgba::from_u8_array([53, 132, 228, 255])),
+            highlight_text: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            button_face: Some(Srgba::from_u8_array([54, 54, 54, 255])),
+            button_text: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            field: None,
+            field_text: None,
+            gray_text: Some(Srgba::from_u8_array([255, 255, 255, 128])),
+        }
+    } else {
+        SystemPalette {
+            canvas: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            canvas_text: Some(Srgba::from_u8_array([0, 0, 0, 255])),
+            link_text: Some(Srgba::from_u8_array([26, 95, 180, 255])),
+            visited_text: None,
+            highlight: Some(Srgba::from_u8_array([53, 132, 228, 255])),
+            highlight_text: Some(Srgba::from_u8_array([255, 255, 255, 255])),
+            button_face: Some(Srgba::from_u8_array([240, 240, 240, 255])),
+            button_text: Some(Srgba::from_u8_array([0, 0, 0, 255])),
+            field: None,
+            field_text: None,
+            gray_text: Some(Srgba::from_u8_array([0, 0, 0, 128])),
+        }
+    }
+}
+
 /// See <https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.portal.Settings.html>.
-#[cfg(feature = "color-scheme")]
+#[cfg(any(feature = "color-scheme", feature = "system-colors"))]
 fn parse_color_scheme(value: Value) -> ColorScheme {
     match u32::try_from(value) {
         // > `1`: Prefer dark appearance
@@ -296,12 +769,15 @@ fn parse_contrast(value: Value) -> Contrast {
 #[cfg(feature = "accent-color")]
 fn parse_accent_color(value: Value) -> AccentColor {
     if let Ok((red, green, blue)) = value.downcast() {
-        AccentColor(Some(Srgba {
-            red,
-            green,
-            blue,
-            alpha: 1.0,
-        }))
+        AccentColor(Some(
+            Srgba {
+                red,
+                green,
+                blue,
+                alpha: 1.0,
+            }
+            .into(),
+        ))
     } else {
         AccentColor(None)
     }
@@ -326,3 +802,94 @@ fn parse_double_click(value: Value) -> DoubleClickInterval {
         .map(Duration::from_millis);
     DoubleClickInterval(value)
 }
+
+// https://gitlab.gnome.org/GNOME/gsettings-desktop-schemas/-/blob/6ad9aaea4dc2929770f2fdf9112280aa5081b6de/schemas/org.gnome.desktop.gschema.xml.in#L74
+#[cfg(feature = "time-format")]
+fn parse_clock_format(value: Value) -> TimeFormat {
+    match String::try_from(value) {
+        Ok(s) if s == "12h" => TimeFormat::Twelve,
+        Ok(s) if s == "24h" => TimeFormat::TwentyFour,
+        _ => TimeFormat::NoPreference,
+    }
+}
+
+#[cfg(feature = "caret-blink-interval")]
+fn parse_bool(value: Value) -> Option<bool> {
+    bool::try_from(value).ok()
+}
+
+#[cfg(feature = "caret-blink-interval")]
+fn parse_i32(value: Value) -> Option<i32> {
+    i32::try_from(value).ok()
+}
+
+// `cursor-blink` and `cursor-blink-time` are two independent GSettings keys, but
+// `CaretBlinkInterval` only has one typed representation, so the initial read
+// combines both at once: an explicitly disabled `cursor-blink` wins outright,
+// otherwise we fall back to whatever blink period `cursor-blink-time` reports.
+#[cfg(feature = "caret-blink-interval")]
+fn combine_cursor_blink(enabled: Option<bool>, millis: Option<i32>) -> CaretBlinkInterval {
+    if enabled == Some(false) {
+        return CaretBlinkInterval::Disabled;
+    }
+    match millis.and_then(|ms| u64::try_from(ms).ok()) {
+        Some(ms) if ms > 0 => CaretBlinkInterval::Interval(Duration::from_millis(ms)),
+        _ => CaretBlinkInterval::NoPreference,
+    }
+}
+
+// `SettingChanged` only ever reports the single key that changed, so toggling
+// `cursor-blink` alone can't tell us the current blink period: if it's being
+// disabled we know that outright, but re-enabling it without a following
+// `cursor-blink-time` change leaves us without an interval to report.
+#[cfg(feature = "caret-blink-interval")]
+fn parse_cursor_blink(value: Value, current: CaretBlinkInterval) -> CaretBlinkInterval {
+    match bool::try_from(value) {
+        Ok(false) => CaretBlinkInterval::Disabled,
+        Ok(true) if current.is_disabled() => CaretBlinkInterval::NoPreference,
+        Ok(true) => current,
+        Err(_) => current,
+    }
+}
+
+#[cfg(feature = "caret-blink-interval")]
+fn parse_cursor_blink_time(value: Value) -> CaretBlinkInterval {
+    match i32::try_from(value).ok().and_then(|ms| u64::try_from(ms).ok()) {
+        Some(ms) if ms > 0 => CaretBlinkInterval::Interval(Duration::from_millis(ms)),
+        _ => CaretBlinkInterval::Disabled,
+    }
+}
+
+// https://gitlab.gnome.org/GNOME/gsettings-desktop-schemas/-/blob/6ad9aaea4dc2929770f2fdf9112280aa5081b6de/schemas/org.gnome.desktop.gschema.xml.in#L118
+#[cfg(feature = "font-rendering")]
+fn parse_font_antialiasing(value: Value) -> Antialiasing {
+    match String::try_from(value) {
+        Ok(s) if s == "none" => Antialiasing::None,
+        Ok(s) if s == "grayscale" => Antialiasing::Grayscale,
+        Ok(s) if s == "rgba" => Antialiasing::Subpixel,
+        _ => Antialiasing::NoPreference,
+    }
+}
+
+#[cfg(feature = "font-rendering")]
+fn parse_font_hinting(value: Value) -> Hinting {
+    match String::try_from(value) {
+        Ok(s) if s == "none" => Hinting::None,
+        Ok(s) if s == "slight" => Hinting::Slight,
+        Ok(s) if s == "medium" => Hinting::Medium,
+        Ok(s) if s == "full" => Hinting::Full,
+        _ => Hinting::NoPreference,
+    }
+}
+
+#[cfg(feature = "font-rendering")]
+fn parse_font_rgba_order(value: Value) -> SubpixelOrder {
+    match String::try_from(value) {
+        Ok(s) if s == "rgb" => SubpixelOrder::Rgb,
+        Ok(s) if s == "bgr" => SubpixelOrder::Bgr,
+        Ok(s) if s == "vrgb" => SubpixelOrder::VRgb,
+        Ok(s) if s == "vbgr" => SubpixelOrder::VBgr,
+        Ok(s) if s == "none" => SubpixelOrder::None,
+        _ => SubpixelOrder::NoPreference,
+    }
+}
@@ -32,8 +32,20 @@ macro_rules! impls {
     (@type reduced_transparency) => { ReducedTransparency };
     (@type accent_color) => { AccentColor };
     (@type double_click_interval) => { DoubleClickInterval };
+    (@type time_format) => { TimeFormat };
+    (@type system_colors) => { SystemColors };
+    (@type caret_blink_interval) => { CaretBlinkInterval };
+    (@type text_scale_factor) => { TextScaleFactor };
+    (@type ui_scale_factor) => { UiScaleFactor };
+    (@type system_palette) => { SystemPalette };
+    (@type forced_colors) => { ForcedColors };
+    (@type inverted_colors) => { InvertedColors };
+    (@type reduced_data) => { ReducedData };
+    (@type font_rendering) => { FontRendering };
+    (@type color_gamut) => { ColorGamut };
     (@struct { $($feature:literal $setting:ident),* }) => {
         #[derive(Debug, Default, Clone, Copy, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub(crate) struct AvailablePreferences {
             $(
                 #[cfg(feature = $feature)]
@@ -6,7 +6,7 @@ use std::ops::BitOr;
 /// [stream]: `crate::Preferences::stream`
 /// [subscription]: `crate::Preferences::subscribe`
 #[derive(Debug, Default, Clone, Copy)]
-pub struct Interest(u8);
+pub struct Interest(u32);
 
 macro_rules! impl_interest {
     (impl $name:ident { $(#[cfg($cfg:meta)] $(#[$($meta:meta)*])* $vis:vis const $ident:ident: $ty:ty = $expr:expr;)* }) => {
@@ -64,6 +64,61 @@ impl_interest! {
         /// Retrieve the [`DoubleClickInterval`](`crate::DoubleClickInterval`) preference
         /// and store it in [`Preferences::double_click_interval`](`crate::Preferences::double_click_interval`).
         pub const DoubleClickInterval: Interest = Interest(1 << 5);
+
+        #[cfg(feature = "time-format")]
+        /// Retrieve the [`TimeFormat`](`crate::TimeFormat`) preference
+        /// and store it in [`Preferences::time_format`](`crate::Preferences::time_format`).
+        pub const TimeFormat: Interest = Interest(1 << 6);
+
+        #[cfg(feature = "system-colors")]
+        /// Retrieve the [`SystemColors`](`crate::SystemColors`) preference
+        /// and store it in [`Preferences::system_colors`](`crate::Preferences::system_colors`).
+        pub const SystemColors: Interest = Interest(1 << 7);
+
+        #[cfg(feature = "caret-blink-interval")]
+        /// Retrieve the [`CaretBlinkInterval`](`crate::CaretBlinkInterval`) preference
+        /// and store it in [`Preferences::caret_blink_interval`](`crate::Preferences::caret_blink_interval`).
+        pub const CaretBlinkInterval: Interest = Interest(1 << 8);
+
+        #[cfg(feature = "text-scale-factor")]
+        /// Retrieve the [`TextScaleFactor`](`crate::TextScaleFactor`) preference
+        /// and store it in [`Preferences::text_scale_factor`](`crate::Preferences::text_scale_factor`).
+        pub const TextScaleFactor: Interest = Interest(1 << 9);
+
+        #[cfg(feature = "ui-scale-factor")]
+        /// Retrieve the [`UiScaleFactor`](`crate::UiScaleFactor`) preference
+        /// and store it in [`Preferences::ui_scale_factor`](`crate::Preferences::ui_scale_factor`).
+        pub const UiScaleFactor: Interest = Interest(1 << 10);
+
+        #[cfg(feature = "system-palette")]
+        /// Retrieve the [`SystemPalette`](`crate::SystemPalette`) preference
+        /// and store it in [`Preferences::system_palette`](`crate::Preferences::system_palette`).
+        pub const SystemPalette: Interest = Interest(1 << 11);
+
+        #[cfg(feature = "forced-colors")]
+        /// Retrieve the [`ForcedColors`](`crate::ForcedColors`) preference
+        /// and store it in [`Preferences::forced_colors`](`crate::Preferences::forced_colors`).
+        pub const ForcedColors: Interest = Interest(1 << 12);
+
+        #[cfg(feature = "inverted-colors")]
+        /// Retrieve the [`InvertedColors`](`crate::InvertedColors`) preference
+        /// and store it in [`Preferences::inverted_colors`](`crate::Preferences::inverted_colors`).
+        pub const InvertedColors: Interest = Interest(1 << 13);
+
+        #[cfg(feature = "reduced-data")]
+        /// Retrieve the [`ReducedData`](`crate::ReducedData`) preference
+        /// and store it in [`Preferences::reduced_data`](`crate::Preferences::reduced_data`).
+        pub const ReducedData: Interest = Interest(1 << 14);
+
+        #[cfg(feature = "font-rendering")]
+        /// Retrieve the [`FontRendering`](`crate::FontRendering`) preference
+        /// and store it in [`Preferences::font_rendering`](`crate::Preferences::font_rendering`).
+        pub const FontRendering: Interest = Interest(1 << 15);
+
+        #[cfg(feature = "color-gamut")]
+        /// Retrieve the [`ColorGamut`](`crate::ColorGamut`) preference
+        /// and store it in [`Preferences::color_gamut`](`crate::Preferences::color_gamut`).
+        pub const ColorGamut: Interest = Interest(1 << 16);
     }
 }
 
@@ -85,6 +140,10 @@ impl Interest {
         {
             value |= Interest::ReducedTransparency.0;
         }
+        #[cfg(feature = "inverted-colors")]
+        {
+            value |= Interest::InvertedColors.0;
+        }
         Interest(value)
     };
 }
@@ -103,6 +162,18 @@ impl Interest {
         {
             value |= Interest::DoubleClickInterval.0;
         }
+        #[cfg(feature = "time-format")]
+        {
+            value |= Interest::TimeFormat.0;
+        }
+        #[cfg(feature = "caret-blink-interval")]
+        {
+            value |= Interest::CaretBlinkInterval.0;
+        }
+        #[cfg(feature = "font-rendering")]
+        {
+            value |= Interest::FontRendering.0;
+        }
         Interest(value)
     };
 }
@@ -119,6 +190,11 @@ impl Interest {
     pub fn is_empty(self) -> bool {
         self.0 == 0
     }
+
+    /// Returns `self` with every flag set in `other` cleared.
+    pub(crate) fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
 }
 
 impl BitOr for Interest {
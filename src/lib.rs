@@ -17,7 +17,18 @@
 //! * [`Contrast`]—The user's preferred contrast level.
 //! * [`ReducedMotion`]—The user's reduced motion preference.
 //! * [`ReducedTransparency`]—The user's reduced transparency preference.
+//! * [`ForcedColors`]—Whether an OS-level forced-colors mode is active.
+//! * [`InvertedColors`]—Whether an OS-level color inversion is active.
+//! * [`ReducedData`]—The user's preference for reduced data usage.
 //! * [`DoubleClickInterval`]—The maximum amount of time allowed between the first and second click.
+//! * [`TimeFormat`]—The user's preference for either a 12-hour or 24-hour clock.
+//! * [`SystemColors`]—A palette of semantic system UI colors beyond just the accent color.
+//! * [`CaretBlinkInterval`]—How long the text caret stays visible (and hidden) before toggling.
+//! * [`TextScaleFactor`]—The user's accessibility text-scaling/font-scale preference.
+//! * [`UiScaleFactor`]—The display's current UI/DPI scale factor.
+//! * [`SystemPalette`]—The CSS system colors (`Canvas`, `LinkText`, `Highlight`, etc.).
+//! * [`FontRendering`]—The user's text antialiasing, hinting and subpixel order settings.
+//! * [`ColorGamut`]—The widest color gamut the user's display can represent.
 //!
 //! Note that each preference has a corresponding [feature flag](`feature_flags`).
 //! By turning off [default features](https://doc.rust-lang.org/cargo/reference/features.html#the-default-feature)
@@ -45,11 +56,23 @@
 //! Alternatively, there's [`Preferences::subscribe`] which
 //! accepts a simple callback function instead.
 //!
+//! If your app already drives its own run loop (winit, kas, a hand-rolled loop around
+//! `mio`, ...) and you'd rather not have `mundy` spawn a thread, use
+//! [`Preferences::source`] and poll [`PreferencesSource::poll_into`] from that loop
+//! instead.
+//!
 //! ## Errors
 //! Most errors (except some fatal errors at startup) are simply ignored
 //! and the default value for the preference (which is usually `NoPreference`) is returned.
 //! It can be useful to turn on the `log` feature to find out what's going on.
 //!
+//! ## Testing
+//! Since there's no portal, `window` or similar on a headless CI runner, mundy lets you
+//! force preference values via environment variables instead of going through a backend,
+//! e.g. `MUNDY_COLOR_SCHEME=dark`, `MUNDY_CONTRAST=more`, `MUNDY_REDUCED_MOTION=reduce`
+//! or `MUNDY_ACCENT_COLOR=#rrggbb`. A forced preference always wins, even if the
+//! corresponding backend later reports a change.
+//!
 //! <br>
 //!
 //! <small>«*I believe in a universe that doesn't care and people
@@ -58,20 +81,21 @@
 use futures_lite::Stream;
 use pin_project_lite::pin_project;
 use std::time::Duration;
-use stream_utils::Dedup;
+use stream_utils::{Debounce, Dedup, Either, Left, Right};
 
 #[macro_use]
 mod impls;
 mod interest;
 pub use interest::*;
 mod async_rt;
+mod env_overrides;
 #[cfg(feature = "callback")]
 mod callback;
 #[cfg(feature = "callback")]
 pub use callback::*;
-#[cfg(feature = "accent-color")]
+#[cfg(any(feature = "accent-color", feature = "system-colors"))]
 mod color;
-#[cfg(feature = "accent-color")]
+#[cfg(any(feature = "accent-color", feature = "system-colors"))]
 pub use color::*;
 
 #[cfg(not(test))]
@@ -89,6 +113,59 @@ mod stream_utils;
 
 /// Contains platform-specific functionality.
 pub mod platform {
+    /// Windows-specific escape hatches for integrating mundy with a host that
+    /// already pumps its own window messages.
+    #[cfg(target_os = "windows")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "windows")))]
+    pub mod windows {
+        use windows::Win32::Foundation::{LPARAM, WPARAM};
+
+        /// Feeds a Win32 message observed by a host-owned message hook (such as
+        /// winit's `EventLoopBuilderExtWindows::with_msg_hook`) into mundy's own
+        /// `WM_SETTINGCHANGE` handling, so mundy
+        /// doesn't need to install its own process-wide `WH_CALLWNDPROC` hook.
+        ///
+        /// Call this from your message hook for every message you see (or, at
+        /// minimum, for `WM_SETTINGCHANGE`).
+        /// Once this has been called at least once, mundy stops installing its
+        /// own hook for new subscriptions.
+        pub fn on_win_message(message: u32, wparam: WPARAM, lparam: LPARAM) {
+            crate::cfg::any_feature! {
+                crate::imp::on_win_message(message, wparam, lparam);
+            }
+        }
+    }
+
+    /// Linux-specific escape hatches for reading settings that mundy doesn't (yet)
+    /// model as a typed preference.
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+    pub mod linux {
+        use futures_lite::Stream;
+        use zbus::zvariant::OwnedValue;
+
+        /// Reads a single value from the `org.freedesktop.portal.Settings` portal
+        /// by its raw `namespace` and `key`, bypassing mundy's typed preferences.
+        ///
+        /// This is useful for settings mundy doesn't model, such as
+        /// `org.gnome.desktop.interface`'s `font-name`. Returns [`None`] if the portal
+        /// is unreachable or doesn't know about the given `namespace`/`key`.
+        pub fn read_raw(namespace: &'static str, key: &'static str) -> Option<OwnedValue> {
+            crate::imp::read_raw(namespace, key)
+        }
+
+        /// Watches a single value from the `org.freedesktop.portal.Settings` portal
+        /// by its raw `namespace` and `key`, bypassing mundy's typed preferences.
+        ///
+        /// See [`read_raw`] for details.
+        pub fn watch_raw(
+            namespace: &'static str,
+            key: &'static str,
+        ) -> impl Stream<Item = OwnedValue> {
+            crate::imp::watch_raw(namespace, key)
+        }
+    }
+
     /// On Android, mundy requires access to the JVM and the current [`Context`].
     /// To access these objects, mundy uses the [`ndk-context`] crate.
     ///
@@ -107,6 +184,40 @@ pub mod platform {
     #[cfg(any(doc, target_os = "android"))]
     #[cfg_attr(docsrs, doc(cfg(target_os = "android")))]
     pub mod android {
+        /// Seeds mundy from an `android-activity` [`AndroidApp`] handle, and lets
+        /// [`on_main_event`] react to [`MainEvent::ConfigChanged`] directly instead
+        /// of relying on a windowing toolkit's `ScaleFactorChanged` event as a
+        /// stand-in for it, which some toolkits (such as [`winit`]) don't expose.
+        ///
+        /// Call this once, as early as possible (e.g. right after `android_main`
+        /// starts), then pass every [`MainEvent`] you see from your own
+        /// `AndroidApp::poll_events` loop to [`on_main_event`].
+        ///
+        /// [`AndroidApp`]: https://docs.rs/android-activity/latest/android_activity/struct.AndroidApp.html
+        /// [`MainEvent`]: https://docs.rs/android-activity/latest/android_activity/enum.MainEvent.html
+        /// [`winit`]: https://docs.rs/winit
+        pub fn attach(app: &android_activity::AndroidApp) {
+            #[cfg(target_os = "android")]
+            crate::cfg::any_feature! {
+                crate::imp::attach(app);
+            }
+        }
+
+        /// Forwards a [`MainEvent`] observed by your own `AndroidApp::poll_events`
+        /// loop to mundy, so `MainEvent::ConfigChanged` refreshes preferences
+        /// derived from the configuration (such as
+        /// [`ColorScheme`](`crate::ColorScheme`)) directly.
+        ///
+        /// Only meaningful after calling [`attach`].
+        ///
+        /// [`MainEvent`]: https://docs.rs/android-activity/latest/android_activity/enum.MainEvent.html
+        pub fn on_main_event(event: &android_activity::MainEvent) {
+            #[cfg(target_os = "android")]
+            crate::cfg::any_feature! {
+                crate::imp::on_main_event(event);
+            }
+        }
+
         /// When certain preferences such as the [`ColorScheme`](`crate::ColorScheme`) change,
         /// Android calls the `onConfigurationChanged` method on your [`View`] or [`Activity`].
         /// Since there is no way for mundy to override these methods itself,
@@ -154,8 +265,24 @@ pub mod platform {
 /// * `contrast`—Enable support for [`Contrast`] (*default*).
 /// * `reduced-motion`—Enable support for [`ReducedMotion`] (*default*).
 /// * `reduced-transparency`—Enable support for [`ReducedTransparency`] (*default*).
+/// * `forced-colors`—Enable support for [`ForcedColors`] (*default*).
+/// * `inverted-colors`—Enable support for [`InvertedColors`] (*default*).
+/// * `reduced-data`—Enable support for [`ReducedData`] (*default*).
 /// * `accent-color`—Enable support for [`AccentColor`] (*default*).
 /// * `double-click-interval`—Enable support for [`DoubleClickInterval`] (*default*).
+/// * `time-format`—Enable support for [`TimeFormat`] (*default*).
+/// * `system-colors`—Enable support for [`SystemColors`] (*default*).
+/// * `caret-blink-interval`—Enable support for [`CaretBlinkInterval`] (*default*).
+/// * `text-scale-factor`—Enable support for [`TextScaleFactor`] (*default*).
+/// * `ui-scale-factor`—Enable support for [`UiScaleFactor`] (*default*).
+/// * `system-palette`—Enable support for [`SystemPalette`] (*default*).
+/// * `font-rendering`—Enable support for [`FontRendering`] (*default*).
+/// * `color-gamut`—Enable support for [`ColorGamut`] (*default*).
+/// * `reflect`—Implement `bevy_reflect::Reflect` for [`Preferences`] and its fields.
+/// * `serde`—Implement `serde::Serialize`/`serde::Deserialize` for [`Preferences`] and
+///   a subset of its fields, so snapshots can be sent across a process boundary (e.g. a
+///   daemon that owns the OS integration fanning updates out to child processes over
+///   an IPC socket). See [`PreferencesStream::from_snapshots`].
 /// * (Linux) `async-io`—Use `zbus` with `async-io` (*default*).
 /// * (Linux) `tokio`—Use `zbus` with `tokio` instead of `async-io`.
 ///
@@ -177,6 +304,8 @@ pub mod readme_doctest {}
 /// Which fields are filled in is determined by the [`Interest`]
 /// you provide when creating a stream or subscription.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Preferences {
     /// The user's preference for either light or dark mode.
@@ -191,6 +320,15 @@ pub struct Preferences {
     /// The user's reduced transparency preference.
     #[cfg(feature = "reduced-transparency")]
     pub reduced_transparency: ReducedTransparency,
+    /// Whether an OS-level forced-colors mode is active.
+    #[cfg(feature = "forced-colors")]
+    pub forced_colors: ForcedColors,
+    /// Whether an OS-level color inversion is active.
+    #[cfg(feature = "inverted-colors")]
+    pub inverted_colors: InvertedColors,
+    /// The user's preference for reduced data usage.
+    #[cfg(feature = "reduced-data")]
+    pub reduced_data: ReducedData,
     /// The user's current system wide accent color preference.
     #[cfg(feature = "accent-color")]
     pub accent_color: AccentColor,
@@ -198,6 +336,30 @@ pub struct Preferences {
     /// event for it to count as double click.
     #[cfg(feature = "double-click-interval")]
     pub double_click_interval: DoubleClickInterval,
+    /// The user's preference for either a 12-hour or 24-hour clock.
+    #[cfg(feature = "time-format")]
+    pub time_format: TimeFormat,
+    /// A palette of semantic system UI colors beyond just the accent color.
+    #[cfg(feature = "system-colors")]
+    pub system_colors: SystemColors,
+    /// How long the text caret stays visible (and hidden) before toggling again.
+    #[cfg(feature = "caret-blink-interval")]
+    pub caret_blink_interval: CaretBlinkInterval,
+    /// The user's accessibility text-scaling/font-scale preference.
+    #[cfg(feature = "text-scale-factor")]
+    pub text_scale_factor: TextScaleFactor,
+    /// The display's current UI/DPI scale factor.
+    #[cfg(feature = "ui-scale-factor")]
+    pub ui_scale_factor: UiScaleFactor,
+    /// The CSS system colors (`Canvas`, `LinkText`, `Highlight`, etc.).
+    #[cfg(feature = "system-palette")]
+    pub system_palette: SystemPalette,
+    /// The user's text antialiasing, hinting and subpixel order settings.
+    #[cfg(feature = "font-rendering")]
+    pub font_rendering: FontRendering,
+    /// The widest color gamut the user's display can represent.
+    #[cfg(feature = "color-gamut")]
+    pub color_gamut: ColorGamut,
 }
 
 impl Preferences {
@@ -211,13 +373,43 @@ impl Preferences {
     ///
     #[doc = include_str!("doc/caveats.md")]
     pub fn stream(interest: Interest) -> PreferencesStream {
-        let inner = if interest.is_empty() {
+        let overrides = env_overrides::Overrides::read();
+        let live_interest = interest.without(overrides.interest());
+        let inner = if live_interest.is_empty() {
+            imp::default_stream()
+        } else {
+            imp::stream(live_interest)
+        };
+        PreferencesStream {
+            inner: StreamKind::Native {
+                inner: Left(Dedup::new(inner)),
+            },
+            overrides,
+        }
+    }
+
+    /// Like [`Preferences::stream()`], but additionally collapses bursts of
+    /// rapid updates (e.g. several notifications fired for a single user
+    /// action) into a single emission once `duration` has passed without a
+    /// further change.
+    ///
+    /// Use this if your consumer does expensive work (such as repainting) on
+    /// every item and doesn't want to do it once per intermediate notification.
+    ///
+    #[doc = include_str!("doc/caveats.md")]
+    pub fn stream_debounced(interest: Interest, duration: Duration) -> PreferencesStream {
+        let overrides = env_overrides::Overrides::read();
+        let live_interest = interest.without(overrides.interest());
+        let inner = if live_interest.is_empty() {
             imp::default_stream()
         } else {
-            imp::stream(interest)
+            imp::stream(live_interest)
         };
         PreferencesStream {
-            inner: Dedup::new(inner),
+            inner: StreamKind::Native {
+                inner: Right(Debounce::new(Dedup::new(inner), duration)),
+            },
+            overrides,
         }
     }
 
@@ -231,21 +423,41 @@ impl Preferences {
     ///
     #[doc = include_str!("doc/caveats.md")]
     pub fn once_blocking(interest: Interest, timeout: Duration) -> Option<Self> {
-        if interest.is_empty() {
-            return Some(Default::default());
-        }
-        imp::once_blocking(interest, timeout).map(Self::from)
+        let overrides = env_overrides::Overrides::read();
+        let live_interest = interest.without(overrides.interest());
+        let preferences = if live_interest.is_empty() {
+            Default::default()
+        } else {
+            imp::once_blocking(live_interest, timeout).map(Self::from)?
+        };
+        Some(overrides.apply(preferences))
     }
 
-    /// Creates a new subscription for a selection of system preferences given by `interests`.
+    /// Reports which preferences the current platform (and, where relevant, OS version)
+    /// is actually able to report, as opposed to merely being compiled in via feature flags.
     ///
-    /// The provided callback is guaranteed to be called at least once with the initial values
-    /// and is subsequently called when preferences are updated.
+    /// A preference that's missing here will always be read back as its `Default` value,
+    /// which lets you tell an absent capability apart from a genuine "no preference" from
+    /// the user, and hide or disable UI for toggles the platform can't honor.
+    pub fn supported_interests() -> Interest {
+        imp::supported_interests()
+    }
+
+    /// Creates a [`PreferencesSource`] for a selection of system preferences given by
+    /// `interest`, for hosts that already drive their own run loop (winit, kas, a
+    /// hand-rolled loop around `mio`, ...) and don't want `mundy` spawning a thread of
+    /// its own to deliver updates. Poll [`PreferencesSource::poll_into()`] whenever the
+    /// host loop wakes up instead.
+    ///
+    /// On macOS this works because preference change observers are registered on (and
+    /// deliver through) the application's main `CFRunLoop`, which the host is already
+    /// pumping; no extra executor is needed to receive them.
     ///
     #[doc = include_str!("doc/caveats.md")]
-    #[cfg(feature = "callback")]
-    pub fn subscribe(interest: Interest, callback: impl CallbackFn) -> Subscription {
-        Preferences::subscribe_impl(interest, callback)
+    pub fn source(interest: Interest) -> PreferencesSource {
+        PreferencesSource {
+            stream: Self::stream(interest),
+        }
     }
 }
 
@@ -253,7 +465,24 @@ pin_project! {
     /// A stream that continually yields preferences
     /// whenever they are changed. Created by [`Preferences::stream()`].
     pub struct PreferencesStream {
-        #[pin] inner: Dedup<imp::PreferencesStream>,
+        #[pin] inner: StreamKind,
+        overrides: env_overrides::Overrides,
+    }
+}
+
+pin_project! {
+    #[project = StreamKindProj]
+    enum StreamKind {
+        Native {
+            #[pin] inner: Either<Dedup<imp::PreferencesStream>, Debounce<Dedup<imp::PreferencesStream>>>,
+        },
+        // Fed by `PreferencesStream::from_snapshots()`, for callers with no direct OS
+        // access of their own (e.g. a child process of a daemon that owns the real
+        // platform integration and forwards already-resolved snapshots over IPC).
+        #[cfg(feature = "serde")]
+        Snapshots {
+            inner: std::pin::Pin<Box<dyn Stream<Item = Preferences> + Send>>,
+        },
     }
 }
 
@@ -268,7 +497,78 @@ impl Stream for PreferencesStream {
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
         let this = self.project();
-        this.inner.poll_next(cx).map(|o| o.map(Preferences::from))
+        match this.inner.project() {
+            StreamKindProj::Native { inner } => inner
+                .poll_next(cx)
+                .map(|o| o.map(|p| this.overrides.apply(Preferences::from(p)))),
+            #[cfg(feature = "serde")]
+            StreamKindProj::Snapshots { inner } => inner.as_mut().poll_next(cx),
+        }
+    }
+}
+
+impl PreferencesStream {
+    /// Builds a [`PreferencesStream`] from an externally supplied sequence of
+    /// already-resolved [`Preferences`] snapshots, for a process with no direct OS
+    /// access of its own that instead receives forwarded updates from a parent process
+    /// that owns the real platform integration (the daemon/IPC-socket model). This
+    /// generalizes the same idea behind the crate's built-in single-item fallback
+    /// stream to a long-lived, externally driven sequence of updates, so the child
+    /// process can consume them through the same stream/subscription API as every
+    /// other backend.
+    ///
+    /// Snapshots are passed through as-is: since they're assumed to already be fully
+    /// resolved by the parent, this doesn't re-apply `MUNDY_*` environment overrides.
+    #[cfg(feature = "serde")]
+    pub fn from_snapshots(snapshots: impl Stream<Item = Preferences> + Send + 'static) -> Self {
+        PreferencesStream {
+            inner: StreamKind::Snapshots {
+                inner: Box::pin(snapshots),
+            },
+            overrides: env_overrides::Overrides::default(),
+        }
+    }
+}
+
+pin_project! {
+    /// A handle created by [`Preferences::source()`] that lets a host with its own run
+    /// loop (rather than an async executor) drive preference delivery without `mundy`
+    /// spawning a thread.
+    pub struct PreferencesSource {
+        #[pin] stream: PreferencesStream,
+    }
+}
+
+#[cfg(test)]
+static_assertions::assert_impl_all!(PreferencesSource: Send);
+
+impl PreferencesSource {
+    /// Gives direct access to the underlying [`PreferencesStream`], for hosts that
+    /// already have an executor/waker of their own and would rather drive the stream
+    /// themselves (e.g. by combining it with other streams).
+    pub fn stream(self: std::pin::Pin<&mut Self>) -> std::pin::Pin<&mut PreferencesStream> {
+        self.project().stream
+    }
+
+    /// Polls for preference updates and passes every one that's ready to `callback`,
+    /// without blocking. Call this whenever your run loop wakes up.
+    ///
+    /// Returns `true` if the source may still produce updates, or `false` once the
+    /// underlying platform stream has ended (e.g. on a platform without a backend, right
+    /// after the initial snapshot).
+    pub fn poll_into(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        callback: &mut impl FnMut(Preferences),
+    ) -> bool {
+        let mut stream = self.project().stream;
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                std::task::Poll::Ready(Some(preferences)) => callback(preferences),
+                std::task::Poll::Ready(None) => return false,
+                std::task::Poll::Pending => return true,
+            }
+        }
     }
 }
 
@@ -280,6 +580,11 @@ impls! {
         "reduced-motion" reduced_motion,
         "accent-color" accent_color,
         "double-click-interval" double_click_interval,
+        "time-format" time_format,
+        "system-colors" system_colors,
+        "caret-blink-interval" caret_blink_interval,
+        "font-rendering" font_rendering,
+        "system-palette" system_palette,
     };
 
     #[cfg(windows)]
@@ -289,7 +594,15 @@ impls! {
         "reduced-motion" reduced_motion,
         "accent-color" accent_color,
         "reduced-transparency" reduced_transparency,
+        "forced-colors" forced_colors,
+        "inverted-colors" inverted_colors,
         "double-click-interval" double_click_interval,
+        "system-colors" system_colors,
+        "caret-blink-interval" caret_blink_interval,
+        "text-scale-factor" text_scale_factor,
+        "font-rendering" font_rendering,
+        "color-gamut" color_gamut,
+        "system-palette" system_palette,
     };
 
     #[cfg(target_os = "macos")]
@@ -298,8 +611,16 @@ impls! {
         "contrast" contrast,
         "reduced-motion" reduced_motion,
         "reduced-transparency" reduced_transparency,
+        "inverted-colors" inverted_colors,
         "accent-color" accent_color,
         "double-click-interval" double_click_interval,
+        "time-format" time_format,
+        "system-colors" system_colors,
+        "caret-blink-interval" caret_blink_interval,
+        "ui-scale-factor" ui_scale_factor,
+        "font-rendering" font_rendering,
+        "color-gamut" color_gamut,
+        "system-palette" system_palette,
     };
 
     #[cfg(all(target_family = "wasm", target_os = "unknown"))]
@@ -309,6 +630,13 @@ impls! {
         "reduced-motion" reduced_motion,
         "accent-color" accent_color,
         "reduced-transparency" reduced_transparency,
+        "forced-colors" forced_colors,
+        "inverted-colors" inverted_colors,
+        "reduced-data" reduced_data,
+        "time-format" time_format,
+        "ui-scale-factor" ui_scale_factor,
+        "system-palette" system_palette,
+        "color-gamut" color_gamut,
     };
 
     #[cfg(target_os = "android")]
@@ -316,7 +644,10 @@ impls! {
         "color-scheme" color_scheme,
         "contrast" contrast,
         "reduced-motion" reduced_motion,
+        "reduced-transparency" reduced_transparency,
         "accent-color" accent_color,
+        "text-scale-factor" text_scale_factor,
+        "time-format" time_format,
     };
 }
 
@@ -329,19 +660,25 @@ impls! {
 ///
 /// </summary>
 ///
-/// * Linux: `org.freedesktop.appearance color-scheme` from the [XDG Settings portal][xdg].
+/// * Linux: `org.freedesktop.appearance color-scheme` from the [XDG Settings portal][xdg],
+///   falling back to the `org.gnome.desktop.interface color-scheme` `gsettings` key
+///   if the portal itself is unreachable.
 /// * Windows: [`UISettings.GetColorValue(UIColorType::Foreground)`](https://learn.microsoft.com/en-us/windows/apps/desktop/modernize/ui/apply-windows-themes#know-when-dark-mode-is-enabled)
 /// * macOS: `NSApplication.effectiveAppearance`
 /// * Web: `@media (prefers-color-scheme: ...)`
-/// * Android: [`Configuration.uiMode`]
+/// * Android: [`Configuration.uiMode`], or directly from an attached [`AndroidApp`]'s
+///   configuration if [`crate::platform::android::attach`] was called.
 ///
 /// </details>
 ///
 /// [`prefers-color-scheme`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme
+/// [`AndroidApp`]: https://docs.rs/android-activity/latest/android_activity/struct.AndroidApp.html
 /// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
 /// [`Configuration.uiMode`]: https://developer.android.com/reference/android/content/res/Configuration#uiMode
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
-#[cfg(feature = "color-scheme")]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(any(feature = "color-scheme", feature = "system-colors"))]
 pub enum ColorScheme {
     /// Indicates that the user has not expressed an active preference,
     /// that the current platform doesn't support a color scheme preference
@@ -354,7 +691,7 @@ pub enum ColorScheme {
     Dark,
 }
 
-#[cfg(feature = "color-scheme")]
+#[cfg(any(feature = "color-scheme", feature = "system-colors"))]
 impl ColorScheme {
     pub fn is_no_preference(self) -> bool {
         matches!(self, ColorScheme::NoPreference)
@@ -379,7 +716,7 @@ impl ColorScheme {
 /// </summary>
 ///
 /// * Linux: `org.freedesktop.appearance contrast` from the [XDG Settings portal][xdg].
-/// * Windows: [`AccessibilitySettings.HighContrast`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.accessibilitysettings.highcontrast)
+/// * Windows: [`SystemParametersInfoW(SPI_GETHIGHCONTRAST, ..)`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-highcontrastw)
 /// * macOS: [`accessibilityDisplayShouldIncreaseContrast`](https://developer.apple.com/documentation/appkit/nsworkspace/1526290-accessibilitydisplayshouldincrea)
 /// * Web: `@media (prefers-contrast: ...)`
 /// * Android: `Settings.Secure.ACCESSIBILITY_HIGH_TEXT_CONTRAST_ENABLED` and [`UiModeManager.getContrast`]
@@ -390,6 +727,8 @@ impl ColorScheme {
 /// [`UiModeManager.getContrast`]: https://developer.android.com/reference/android/app/UiModeManager#getContrast()
 /// [`prefers-contrast`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-contrast
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "contrast")]
 pub enum Contrast {
     /// Indicates that the user has not expressed an active preference,
@@ -449,6 +788,8 @@ impl Contrast {
 /// [`Settings.Global.ANIMATOR_DURATION_SCALE`]: https://developer.android.com/reference/android/provider/Settings.Global#ANIMATOR_DURATION_SCALE
 /// [`prefers-reduced-motion`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-motion
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "reduced-motion")]
 pub enum ReducedMotion {
     /// Indicates that the user has not expressed an active preference,
@@ -484,14 +825,17 @@ impl ReducedMotion {
 /// * Windows: [`UISettings.AdvancedEffectsEnabled`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.uisettings.advancedeffectsenabled)
 /// * macOS: [`accessibilityDisplayShouldReduceTransparency`](https://developer.apple.com/documentation/appkit/nsworkspace/1533006-accessibilitydisplayshouldreduce)
 /// * Web: `@media (prefers-reduced-transparency: ...)`
+/// * Android: [`Settings.Global.TRANSITION_ANIMATION_SCALE`]
 /// * Linux: Unsupported
-/// * Android: Unsupported
 ///
 /// </details>
 ///
 /// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
+/// [`Settings.Global.TRANSITION_ANIMATION_SCALE`]: https://developer.android.com/reference/android/provider/Settings.Global#TRANSITION_ANIMATION_SCALE
 /// [`prefers-reduced-transparency`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-transparency
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "reduced-transparency")]
 pub enum ReducedTransparency {
     /// Indicates that the user has not expressed an active preference,
@@ -515,6 +859,138 @@ impl ReducedTransparency {
     }
 }
 
+/// Indicates that the user has enabled an OS-level forced-colors mode, which overrides
+/// author-specified colors with a limited, user-chosen palette. This corresponds to the
+/// [`forced-colors`] CSS media feature.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Windows: High contrast mode, via [`SPI_GETHIGHCONTRAST`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfow).
+/// * Web: `@media (forced-colors: ...)`
+/// * Linux: Unsupported
+/// * macOS: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+///
+/// [`forced-colors`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/forced-colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "forced-colors")]
+pub enum ForcedColors {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a forced colors preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that a forced-colors mode is active.
+    Active,
+}
+
+#[cfg(feature = "forced-colors")]
+impl ForcedColors {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, ForcedColors::NoPreference)
+    }
+
+    pub fn is_active(self) -> bool {
+        matches!(self, ForcedColors::Active)
+    }
+}
+
+/// Indicates that the user has enabled an OS-level color inversion, e.g. to compensate for
+/// light sensitivity. This corresponds to the [`inverted-colors`] CSS media feature.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * macOS: [`accessibilityDisplayShouldInvertColors`](https://developer.apple.com/documentation/appkit/nsworkspace/1528916-accessibilitydisplayshouldinver)
+/// * Web: `@media (inverted-colors: ...)`
+/// * Windows: The `Active`/`FilterType` values of the Color Filters feature, under
+///   `HKEY_CURRENT_USER\Software\Microsoft\ColorFiltering`.
+/// * Linux: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+///
+/// [`inverted-colors`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/inverted-colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "inverted-colors")]
+pub enum InvertedColors {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a color inversion preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the display's colors are being inverted.
+    Inverted,
+}
+
+#[cfg(feature = "inverted-colors")]
+impl InvertedColors {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, InvertedColors::NoPreference)
+    }
+
+    pub fn is_inverted(self) -> bool {
+        matches!(self, InvertedColors::Inverted)
+    }
+}
+
+/// Indicates that the user has asked to minimize the amount of data transferred over the
+/// network, e.g. by not auto-playing videos. This corresponds to the [`prefers-reduced-data`]
+/// CSS media feature.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Web: `@media (prefers-reduced-data: ...)`
+/// * Linux: Unsupported
+/// * Windows: Unsupported
+/// * macOS: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+///
+/// [`prefers-reduced-data`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-data
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "reduced-data")]
+pub enum ReducedData {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a reduced data preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user prefers reduced data usage.
+    Reduce,
+}
+
+#[cfg(feature = "reduced-data")]
+impl ReducedData {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, ReducedData::NoPreference)
+    }
+
+    pub fn is_reduce(self) -> bool {
+        matches!(self, ReducedData::Reduce)
+    }
+}
+
 /// The user's current system wide accent color preference.
 ///
 /// <details>
@@ -526,16 +1002,42 @@ impl ReducedTransparency {
 ///
 /// * Linux: `org.freedesktop.appearance accent-color` from the [XDG Settings portal][xdg].
 /// * Windows: [`UISettings.GetColorValue(UIColorType::Accent)`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.uisettings)
-/// * macOS: [`NSColor.controlAccentColor`](https://developer.apple.com/documentation/appkit/nscolor/3000782-controlaccentcolor)
+/// * macOS: [`NSColor.controlAccentColor`](https://developer.apple.com/documentation/appkit/nscolor/3000782-controlaccentcolor),
+///   read in the Display P3 color space when available so vivid accent colors chosen on
+///   a wide-gamut display aren't clamped down to sRGB.
 /// * Web: The [`AccentColor`](https://developer.mozilla.org/en-US/docs/Web/CSS/system-color#accentcolor) system color.
-/// * Android: `android.R.attr.colorAccent`
+/// * Android: `android.R.color.system_accent1_500` (the Material You dynamic accent color)
 ///
 /// </details>
 ///
+/// The color is kept in whichever gamut it was read in; call
+/// [`to_srgba`](WideGamutColor::to_srgba) on it to gamut-map down to sRGB.
+///
 /// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "accent-color")]
-pub struct AccentColor(pub Option<Srgba>);
+pub struct AccentColor(pub Option<WideGamutColor>);
+
+impl AccentColor {
+    /// Picks black or white text, whichever has the higher WCAG contrast ratio against
+    /// this accent color once composited over `background`. Returns [`None`] if there's
+    /// no accent color to begin with.
+    ///
+    /// This doesn't guarantee the result clears any particular contrast threshold (e.g.
+    /// WCAG AA's 4.5:1 for normal text, 3:1 for large text) — only that it's the better
+    /// of the two options; check [`Srgba::contrast_ratio`] against the result yourself
+    /// if you need to know whether it actually passes.
+    pub fn readable_foreground(self, background: Srgba) -> Option<Srgba> {
+        let accent = self.0?.to_srgba().composited_over(background);
+        if accent.contrast_ratio(color::BLACK) >= accent.contrast_ratio(color::WHITE) {
+            Some(color::BLACK)
+        } else {
+            Some(color::WHITE)
+        }
+    }
+}
 
 /// The maximum amount of time that may occur between the first and second click
 /// event for it to count as double click.
@@ -559,5 +1061,475 @@ pub struct AccentColor(pub Option<Srgba>);
 ///
 /// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "double-click-interval")]
 pub struct DoubleClickInterval(pub Option<std::time::Duration>);
+
+/// The caret/text-cursor blink interval, i.e. how long the caret stays visible (and how long
+/// it stays hidden) before toggling again. This is the companion preference to
+/// [`DoubleClickInterval`], typically consumed by an editor's blink manager.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux (GNOME-only): `org.gnome.desktop.interface cursor-blink`/`cursor-blink-time` from the [XDG Settings portal][xdg].
+/// * Windows: [`GetCaretBlinkTime`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getcaretblinktime)
+/// * macOS: The `NSTextInsertionPointBlinkPeriodOn`/`NSTextInsertionPointBlinkPeriodOff` user defaults.
+/// * Web: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+///
+/// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "caret-blink-interval")]
+pub enum CaretBlinkInterval {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a caret blink interval preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user has disabled caret blinking entirely.
+    Disabled,
+    /// Indicates that the caret should toggle its visibility at the given interval.
+    Interval(Duration),
+}
+
+#[cfg(feature = "caret-blink-interval")]
+impl CaretBlinkInterval {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, CaretBlinkInterval::NoPreference)
+    }
+
+    pub fn is_disabled(self) -> bool {
+        matches!(self, CaretBlinkInterval::Disabled)
+    }
+
+    pub fn interval(self) -> Option<Duration> {
+        match self {
+            CaretBlinkInterval::Interval(duration) => Some(duration),
+            _ => None,
+        }
+    }
+}
+
+/// The user's accessibility text-scaling/font-scale preference, where `1.0`
+/// is 100% (no scaling).
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Windows: [`UISettings.TextScaleFactor()`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.uisettings.textscalefactor)
+/// * Android: `Configuration.fontScale`
+/// * Linux: Unsupported
+/// * macOS: Unsupported
+/// * Web: Unsupported
+///
+/// </details>
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "text-scale-factor")]
+pub struct TextScaleFactor(pub Option<f64>);
+
+/// The display's current UI/DPI scale factor, e.g. `2.0` for a 200% ("Retina"/HiDPI)
+/// display. This is distinct from [`TextScaleFactor`], which tracks an accessibility
+/// font-size preference that's independent of display density.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * macOS: `NSScreen.backingScaleFactor()`
+/// * Web: `window.devicePixelRatio`
+/// * Linux: Unsupported
+/// * Windows: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "ui-scale-factor")]
+pub struct UiScaleFactor(pub Option<f64>);
+
+/// The user's preference for either a 12-hour or 24-hour clock.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux (GNOME-only): `org.gnome.desktop.interface clock-format` from the [XDG Settings portal][xdg].
+/// * macOS: The current locale's hour cycle.
+/// * Web: `Intl.DateTimeFormat().resolvedOptions().hour12`.
+/// * Android: `android.text.format.DateFormat.is24HourFormat`.
+/// * Windows: Unsupported
+///
+/// </details>
+///
+/// [xdg]: https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.impl.portal.Settings.html
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "time-format")]
+pub enum TimeFormat {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a time format preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user prefers a 12-hour clock (e.g. `2:00 PM`).
+    Twelve,
+    /// Indicates that the user prefers a 24-hour clock (e.g. `14:00`).
+    TwentyFour,
+}
+
+#[cfg(feature = "time-format")]
+impl TimeFormat {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, TimeFormat::NoPreference)
+    }
+
+    pub fn is_twelve(self) -> bool {
+        matches!(self, TimeFormat::Twelve)
+    }
+
+    pub fn is_twenty_four(self) -> bool {
+        matches!(self, TimeFormat::TwentyFour)
+    }
+}
+
+/// A palette of semantic system UI colors beyond just the [`AccentColor`], e.g. label
+/// text, control backgrounds and separators.
+///
+/// Unlike [`AccentColor`], not every platform exposes every slot (and some only do so
+/// approximately), so each field is independently optional: it's [`None`] if the
+/// platform doesn't report that particular color.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux (GNOME-only): Approximated from [`ColorScheme`] using libadwaita's default
+///   palette, since GTK/libadwaita's named colors are computed in CSS rather than
+///   exposed through a portal setting.
+/// * Windows: [`UISettings.GetColorValue`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.uisettings.getcolorvalue)
+///   for the slots that have a standard [`UIColorType`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.viewmanagement.uicolortype); there's no dedicated
+///   "separator" or "placeholder text" entry, so those are left unset, as is
+///   `selected_content_background`.
+/// * macOS: [`NSColor.labelColor`](https://developer.apple.com/documentation/appkit/nscolor/1524741-labelcolor) and its siblings (`controlBackgroundColor`,
+///   `selectedContentBackgroundColor`, `separatorColor`, `placeholderTextColor`),
+///   resolved to concrete sRGB components the same way [`AccentColor`] is.
+/// * Web: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "system-colors")]
+pub struct SystemColors {
+    /// The color for text labels containing primary content.
+    pub label: Option<Srgba>,
+    /// The color for the background of controls.
+    pub control_background: Option<Srgba>,
+    /// The color for the background of selected/highlighted content.
+    pub selected_content_background: Option<Srgba>,
+    /// The color for thin separator or border lines.
+    pub separator: Option<Srgba>,
+    /// The color for placeholder text in controls.
+    pub placeholder_text: Option<Srgba>,
+}
+
+/// The CSS [system colors](https://developer.mozilla.org/en-US/docs/Web/CSS/system-color), e.g.
+/// `Canvas`, `LinkText` and `Highlight`.
+///
+/// Unlike [`SystemColors`], which exposes a fixed, cross-platform set of semantic slots,
+/// this mirrors the CSS system color keywords directly. Each field is [`None`] if the
+/// platform doesn't report that particular color.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux (GNOME-only): Approximated from [`ColorScheme`] using libadwaita's default
+///   palette, for the same reason [`SystemColors`] is. `visited_text`, `field` and
+///   `field_text` have no GTK/libadwaita equivalent and are left unset.
+/// * Windows: [`GetSysColor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolor)
+///   for the slots that have a matching `COLOR_*` index; `visited_text`, `field` and
+///   `field_text` have no dedicated index and are left unset.
+/// * macOS: [`NSColor`](https://developer.apple.com/documentation/appkit/nscolor) system
+///   colors (`windowBackgroundColor`, `textColor`, `linkColor`, …), resolved the same way
+///   [`SystemColors`] is. `visited_text` and `field_text` have no AppKit equivalent and
+///   are left unset.
+/// * Web: `getComputedStyle` on an offscreen element with `color` set to the matching
+///   CSS system color keyword, detected via the same `transitionstart` trick used by
+///   [`AccentColor`].
+/// * Android: Unsupported
+///
+/// </details>
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg(feature = "system-palette")]
+pub struct SystemPalette {
+    /// The CSS `Canvas` system color: background of application content or documents.
+    pub canvas: Option<Srgba>,
+    /// The CSS `CanvasText` system color: text in application content or documents.
+    pub canvas_text: Option<Srgba>,
+    /// The CSS `LinkText` system color: text of non-visited links.
+    pub link_text: Option<Srgba>,
+    /// The CSS `VisitedText` system color: text of visited links.
+    pub visited_text: Option<Srgba>,
+    /// The CSS `Highlight` system color: background of selected/highlighted text.
+    pub highlight: Option<Srgba>,
+    /// The CSS `HighlightText` system color: text of selected/highlighted text.
+    pub highlight_text: Option<Srgba>,
+    /// The CSS `ButtonFace` system color: background of a push button.
+    pub button_face: Option<Srgba>,
+    /// The CSS `ButtonText` system color: text of a push button.
+    pub button_text: Option<Srgba>,
+    /// The CSS `Field` system color: background of an input field.
+    pub field: Option<Srgba>,
+    /// The CSS `FieldText` system color: text of an input field.
+    pub field_text: Option<Srgba>,
+    /// The CSS `GrayText` system color: text of disabled controls.
+    pub gray_text: Option<Srgba>,
+}
+
+/// The user's text antialiasing, hinting and subpixel order settings, so renderers can
+/// match the platform's glyph rendering instead of reimplementing the per-OS probing
+/// themselves.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux (GNOME-only): `org.gnome.desktop.interface`'s `font-antialiasing`,
+///   `font-hinting` and `font-rgba-order` keys, which fontconfig is configured
+///   to resolve to on a GNOME session.
+/// * Windows: `SystemParametersInfo`'s `SPI_GETFONTSMOOTHING`,
+///   `SPI_GETFONTSMOOTHINGTYPE` and `SPI_GETFONTSMOOTHINGORIENTATION`. Windows doesn't
+///   expose a separate hinting level, so [`FontRendering::hinting`] is always
+///   [`Hinting::NoPreference`] there.
+/// * macOS: `AppleFontSmoothing` from `NSUserDefaults`. Subpixel antialiasing was
+///   removed from AppKit's text rendering starting with macOS Mojave, so
+///   [`FontRendering::subpixel_order`] is always [`SubpixelOrder::NoPreference`] there,
+///   and [`FontRendering::hinting`] is always [`Hinting::NoPreference`] as there's no
+///   separate hinting level to read either.
+/// * Web: Unsupported
+/// * Android: Unsupported
+///
+/// </details>
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "font-rendering")]
+pub struct FontRendering {
+    /// The user's antialiasing mode.
+    pub antialiasing: Antialiasing,
+    /// The user's hinting level.
+    pub hinting: Hinting,
+    /// The user's subpixel/RGBA order, meaningful only when
+    /// [`antialiasing`](Self::antialiasing) is [`Antialiasing::Subpixel`].
+    pub subpixel_order: SubpixelOrder,
+}
+
+/// The user's font antialiasing mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "font-rendering")]
+pub enum Antialiasing {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support an antialiasing preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user has disabled antialiasing entirely.
+    None,
+    /// Indicates that the user prefers grayscale antialiasing.
+    Grayscale,
+    /// Indicates that the user prefers subpixel (LCD) antialiasing.
+    Subpixel,
+}
+
+#[cfg(feature = "font-rendering")]
+impl Antialiasing {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, Antialiasing::NoPreference)
+    }
+
+    pub fn is_none(self) -> bool {
+        matches!(self, Antialiasing::None)
+    }
+
+    pub fn is_grayscale(self) -> bool {
+        matches!(self, Antialiasing::Grayscale)
+    }
+
+    pub fn is_subpixel(self) -> bool {
+        matches!(self, Antialiasing::Subpixel)
+    }
+}
+
+/// The user's font hinting level.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "font-rendering")]
+pub enum Hinting {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a hinting preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user has disabled hinting entirely.
+    None,
+    /// Indicates that the user prefers slight hinting.
+    Slight,
+    /// Indicates that the user prefers medium hinting.
+    Medium,
+    /// Indicates that the user prefers full hinting.
+    Full,
+}
+
+#[cfg(feature = "font-rendering")]
+impl Hinting {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, Hinting::NoPreference)
+    }
+
+    pub fn is_none(self) -> bool {
+        matches!(self, Hinting::None)
+    }
+
+    pub fn is_slight(self) -> bool {
+        matches!(self, Hinting::Slight)
+    }
+
+    pub fn is_medium(self) -> bool {
+        matches!(self, Hinting::Medium)
+    }
+
+    pub fn is_full(self) -> bool {
+        matches!(self, Hinting::Full)
+    }
+}
+
+/// The user's subpixel/RGBA order for subpixel antialiasing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "font-rendering")]
+pub enum SubpixelOrder {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a subpixel order preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that subpixel antialiasing is disabled, so there's no RGBA order.
+    None,
+    /// Horizontal red-green-blue subpixel order.
+    Rgb,
+    /// Horizontal blue-green-red subpixel order.
+    Bgr,
+    /// Vertical red-green-blue subpixel order.
+    VRgb,
+    /// Vertical blue-green-red subpixel order.
+    VBgr,
+}
+
+#[cfg(feature = "font-rendering")]
+impl SubpixelOrder {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, SubpixelOrder::NoPreference)
+    }
+
+    pub fn is_none(self) -> bool {
+        matches!(self, SubpixelOrder::None)
+    }
+}
+
+/// The widest color gamut the user's display can represent. This corresponds to the
+/// [`color-gamut`] CSS media feature.
+///
+/// <details>
+/// <summary style="cursor: pointer">
+///
+/// #### Platform-specific Sources
+///
+/// </summary>
+///
+/// * Linux: Unsupported
+/// * Windows: The main display's advanced color info, via
+///   [`DisplayInformation.GetAdvancedColorInfo`]. Windows only distinguishes between
+///   standard dynamic range, wide color gamut and high dynamic range, so
+///   [`ColorGamut::Rec2020`] here means "the display is HDR-capable", not that it
+///   necessarily covers the entire Rec. 2020 gamut.
+/// * macOS: `NSScreen.canRepresentDisplayGamut` on the main screen. AppKit only exposes
+///   `NSDisplayGamut::SRGB` and `NSDisplayGamut::P3`, so [`ColorGamut::Rec2020`] is never
+///   reported there.
+/// * Web: `@media (color-gamut: ...)`
+/// * Android: Unsupported
+///
+/// </details>
+///
+/// [`color-gamut`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/color-gamut
+/// [`DisplayInformation.GetAdvancedColorInfo`]: https://learn.microsoft.com/en-us/uwp/api/windows.graphics.display.displayinformation.getadvancedcolorinfo
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg(feature = "color-gamut")]
+pub enum ColorGamut {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a color gamut preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the display can represent (at most) the sRGB gamut.
+    Srgb,
+    /// Indicates that the display can represent the P3 gamut.
+    P3,
+    /// Indicates that the display can represent the Rec. 2020 gamut.
+    Rec2020,
+}
+
+#[cfg(feature = "color-gamut")]
+impl ColorGamut {
+    pub fn is_no_preference(self) -> bool {
+        matches!(self, ColorGamut::NoPreference)
+    }
+
+    pub fn is_srgb(self) -> bool {
+        matches!(self, ColorGamut::Srgb)
+    }
+
+    pub fn is_p3(self) -> bool {
+        matches!(self, ColorGamut::P3)
+    }
+
+    pub fn is_rec2020(self) -> bool {
+        matches!(self, ColorGamut::Rec2020)
+    }
+}
@@ -8,12 +8,34 @@ use crate::ColorScheme;
 use crate::Contrast;
 #[cfg(feature = "double-click-interval")]
 use crate::DoubleClickInterval;
+#[cfg(feature = "caret-blink-interval")]
+use crate::CaretBlinkInterval;
 #[cfg(feature = "reduced-motion")]
 use crate::ReducedMotion;
 #[cfg(feature = "reduced-transparency")]
 use crate::ReducedTransparency;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
+#[cfg(feature = "system-colors")]
+use crate::SystemColors;
+#[cfg(feature = "ui-scale-factor")]
+use crate::UiScaleFactor;
+#[cfg(feature = "font-rendering")]
+use crate::{Antialiasing, FontRendering, Hinting, SubpixelOrder};
+#[cfg(feature = "color-gamut")]
+use crate::ColorGamut;
+#[cfg(feature = "system-palette")]
+use crate::SystemPalette;
+#[cfg(any(
+    feature = "accent-color",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
+use crate::Srgba;
 #[cfg(feature = "accent-color")]
-use crate::{AccentColor, Srgba};
+use crate::{AccentColor, DisplayP3, WideGamutColor};
 use crate::{AvailablePreferences, Interest};
 #[cfg(feature = "_macos-observable")]
 use futures_channel::mpsc;
@@ -27,11 +49,29 @@ use objc2_app_kit::NSEvent;
 use objc2_app_kit::NSWorkspace;
 #[cfg(feature = "color-scheme")]
 use objc2_app_kit::{NSAppearance, NSAppearanceNameAqua, NSAppearanceNameDarkAqua};
-#[cfg(feature = "accent-color")]
+#[cfg(any(
+    feature = "accent-color",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
 use objc2_app_kit::{NSColor, NSColorSpace};
+#[cfg(any(feature = "ui-scale-factor", feature = "color-gamut"))]
+use objc2_app_kit::NSScreen;
+#[cfg(feature = "color-gamut")]
+use objc2_app_kit::NSDisplayGamut;
 use objc2_foundation::MainThreadMarker;
 #[cfg(feature = "color-scheme")]
 use objc2_foundation::NSArray;
+#[cfg(feature = "time-format")]
+use objc2_foundation::{NSDateFormatter, NSLocale};
+#[cfg(any(
+    feature = "time-format",
+    feature = "caret-blink-interval",
+    feature = "font-rendering"
+))]
+use objc2_foundation::NSString;
+#[cfg(any(feature = "caret-blink-interval", feature = "font-rendering"))]
+use objc2_foundation::{ns_string, NSUserDefaults};
 #[cfg(feature = "_macos-observable")]
 use observer::{Observer, ObserverRegistration};
 use pin_project_lite::pin_project;
@@ -92,6 +132,75 @@ pub(crate) fn once_blocking(
     Some(get_preferences(interest, &application))
 }
 
+// Every preference here is read straight off AppKit/NSUserDefaults, which are always
+// present, so there's no platform-specific capability gap to report on macOS -- just
+// the fixed set of preferences this backend actually sources (`get_preferences` below).
+// `ForcedColors`, `ReducedData` and `TextScaleFactor` have no AppKit/NSUserDefaults
+// equivalent on macOS, so they're left unsupported.
+pub(crate) fn supported_interests() -> Interest {
+    #[allow(unused_mut)]
+    let mut supported = Interest::default();
+
+    #[cfg(feature = "color-scheme")]
+    {
+        supported = supported | Interest::ColorScheme;
+    }
+    #[cfg(feature = "contrast")]
+    {
+        supported = supported | Interest::Contrast;
+    }
+    #[cfg(feature = "reduced-motion")]
+    {
+        supported = supported | Interest::ReducedMotion;
+    }
+    #[cfg(feature = "reduced-transparency")]
+    {
+        supported = supported | Interest::ReducedTransparency;
+    }
+    #[cfg(feature = "inverted-colors")]
+    {
+        supported = supported | Interest::InvertedColors;
+    }
+    #[cfg(feature = "accent-color")]
+    {
+        supported = supported | Interest::AccentColor;
+    }
+    #[cfg(feature = "double-click-interval")]
+    {
+        supported = supported | Interest::DoubleClickInterval;
+    }
+    #[cfg(feature = "time-format")]
+    {
+        supported = supported | Interest::TimeFormat;
+    }
+    #[cfg(feature = "system-colors")]
+    {
+        supported = supported | Interest::SystemColors;
+    }
+    #[cfg(feature = "caret-blink-interval")]
+    {
+        supported = supported | Interest::CaretBlinkInterval;
+    }
+    #[cfg(feature = "ui-scale-factor")]
+    {
+        supported = supported | Interest::UiScaleFactor;
+    }
+    #[cfg(feature = "font-rendering")]
+    {
+        supported = supported | Interest::FontRendering;
+    }
+    #[cfg(feature = "color-gamut")]
+    {
+        supported = supported | Interest::ColorGamut;
+    }
+    #[cfg(feature = "system-palette")]
+    {
+        supported = supported | Interest::SystemPalette;
+    }
+
+    supported
+}
+
 #[cfg(feature = "_macos-observable")]
 type ObserverRegistrationImpl = Option<ObserverRegistration>;
 
@@ -154,6 +263,11 @@ fn get_preferences(
         preferences.reduced_transparency = get_reduced_transparency();
     }
 
+    #[cfg(feature = "inverted-colors")]
+    if interest.is(Interest::InvertedColors) {
+        preferences.inverted_colors = get_inverted_colors();
+    }
+
     #[cfg(feature = "accent-color")]
     if interest.is(Interest::AccentColor) {
         preferences.accent_color = get_accent_color();
@@ -164,6 +278,41 @@ fn get_preferences(
         preferences.double_click_interval = get_double_click_interval();
     }
 
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        preferences.time_format = get_time_format();
+    }
+
+    #[cfg(feature = "system-colors")]
+    if interest.is(Interest::SystemColors) {
+        preferences.system_colors = get_system_colors();
+    }
+
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        preferences.caret_blink_interval = get_caret_blink_interval();
+    }
+
+    #[cfg(feature = "ui-scale-factor")]
+    if interest.is(Interest::UiScaleFactor) {
+        preferences.ui_scale_factor = get_ui_scale_factor();
+    }
+
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        preferences.font_rendering = get_font_rendering();
+    }
+
+    #[cfg(feature = "color-gamut")]
+    if interest.is(Interest::ColorGamut) {
+        preferences.color_gamut = get_color_gamut();
+    }
+
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        preferences.system_palette = get_system_palette();
+    }
+
     preferences
 }
 
@@ -227,15 +376,56 @@ fn get_reduced_transparency() -> ReducedTransparency {
     }
 }
 
+#[cfg(feature = "inverted-colors")]
+fn get_inverted_colors() -> InvertedColors {
+    let workspace = get_shared_workspace();
+    // SAFETY: Similar as for `get_shared_workspace()`.
+    let invert_colors = unsafe { workspace.accessibilityDisplayShouldInvertColors() };
+    if invert_colors {
+        InvertedColors::Inverted
+    } else {
+        InvertedColors::NoPreference
+    }
+}
+
 #[cfg(feature = "accent-color")]
 fn get_accent_color() -> AccentColor {
     let color = unsafe { NSColor::controlAccentColor() };
-    AccentColor(to_srgb(&color))
+    // Resolving straight to sRGB would silently clamp vivid accent colors chosen on a
+    // Display P3 display, losing information. Try Display P3 first and only fall back
+    // to sRGB if the color can't be resolved in that space at all.
+    let wide_gamut_color = to_display_p3(&color)
+        .map(WideGamutColor::DisplayP3)
+        .or_else(|| to_srgb(&color).map(WideGamutColor::Srgb));
+    AccentColor(wide_gamut_color)
 }
 
 #[cfg(feature = "accent-color")]
+fn to_display_p3(color: &NSColor) -> Option<DisplayP3> {
+    let display_p3 = unsafe { NSColorSpace::displayP3ColorSpace() };
+    // See the comment on `to_srgb` below for why this conversion is needed.
+    let color_in_display_p3 = unsafe { color.colorUsingColorSpace(&display_p3) }?;
+    // We have to cast because on 32-bit platforms, `CGFloat` = f32.
+    Some(DisplayP3 {
+        red: unsafe { color_in_display_p3.redComponent() } as _,
+        green: unsafe { color_in_display_p3.greenComponent() } as _,
+        blue: unsafe { color_in_display_p3.blueComponent() } as _,
+        alpha: unsafe { color_in_display_p3.alphaComponent() } as _,
+    })
+}
+
+#[cfg(any(
+    feature = "accent-color",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
 fn to_srgb(color: &NSColor) -> Option<Srgba> {
     let srgb = unsafe { NSColorSpace::sRGBColorSpace() };
+    // Semantic/dynamic colors (e.g. `NSColor.labelColor`) aren't plain RGBA until
+    // resolved through a color space, and a naive `getRed:green:blue:alpha:` on one
+    // of them raises an exception. `colorUsingColorSpace:` resolves the color under
+    // whatever appearance is currently active (the application's `effectiveAppearance`
+    // in our case), returning `nil` if the conversion isn't possible.
     let color_in_srgb = unsafe { color.colorUsingColorSpace(&srgb) }?;
     // We have to cast because on 32-bit platforms, `CGFloat` = f32.
     Some(Srgba {
@@ -246,9 +436,155 @@ fn to_srgb(color: &NSColor) -> Option<Srgba> {
     })
 }
 
+#[cfg(feature = "system-colors")]
+fn get_system_colors() -> SystemColors {
+    SystemColors {
+        label: to_srgb(&unsafe { NSColor::labelColor() }),
+        control_background: to_srgb(&unsafe { NSColor::controlBackgroundColor() }),
+        selected_content_background: to_srgb(&unsafe {
+            NSColor::selectedContentBackgroundColor()
+        }),
+        separator: to_srgb(&unsafe { NSColor::separatorColor() }),
+        placeholder_text: to_srgb(&unsafe { NSColor::placeholderTextColor() }),
+    }
+}
+
+// AppKit has no dedicated "visited link" or "input field text" color, so those two
+// CSS system-color slots are left unset here.
+#[cfg(feature = "system-palette")]
+fn get_system_palette() -> SystemPalette {
+    SystemPalette {
+        canvas: to_srgb(&unsafe { NSColor::windowBackgroundColor() }),
+        canvas_text: to_srgb(&unsafe { NSColor::textColor() }),
+        link_text: to_srgb(&unsafe { NSColor::linkColor() }),
+        visited_text: None,
+        highlight: to_srgb(&unsafe { NSColor::selectedTextBackgroundColor() }),
+        highlight_text: to_srgb(&unsafe { NSColor::selectedTextColor() }),
+        button_face: to_srgb(&unsafe { NSColor::controlColor() }),
+        button_text: to_srgb(&unsafe { NSColor::controlTextColor() }),
+        field: to_srgb(&unsafe { NSColor::textBackgroundColor() }),
+        field_text: None,
+        gray_text: to_srgb(&unsafe { NSColor::disabledControlTextColor() }),
+    }
+}
+
 #[cfg(feature = "double-click-interval")]
 fn get_double_click_interval() -> DoubleClickInterval {
     // NSTimeInterval: A number of seconds.
     let interval = unsafe { NSEvent::doubleClickInterval() };
     DoubleClickInterval(Duration::try_from_secs_f64(interval).ok())
 }
+
+// `NSTextInsertionPointBlinkPeriodOn`/`Off` are the same user defaults AppKit's own text
+// views consult for blink timing; there's no dedicated Objective-C getter for them.
+#[cfg(feature = "caret-blink-interval")]
+fn get_caret_blink_interval() -> CaretBlinkInterval {
+    // When the user has asked for reduced motion, AppKit stops blinking the caret
+    // regardless of what `NSTextInsertionPointBlinkPeriodOn/Off` say, so report that
+    // here too rather than a timed interval that'll never actually be honored.
+    #[cfg(feature = "reduced-motion")]
+    if get_reduced_motion().is_reduce() {
+        return CaretBlinkInterval::Disabled;
+    }
+
+    let defaults = unsafe { NSUserDefaults::standardUserDefaults() };
+    let on = unsafe { defaults.doubleForKey(caret_blink_period_on_key()) };
+    let off = unsafe { defaults.doubleForKey(caret_blink_period_off_key()) };
+    // A value of `0` here is ambiguous: it's both how an explicitly disabled blink
+    // period is represented and what `doubleForKey:` returns when the default was
+    // never set, so we can only report "no preference" rather than "disabled" here.
+    if on == 0.0 && off == 0.0 {
+        return CaretBlinkInterval::NoPreference;
+    }
+    if on <= 0.0 || off <= 0.0 {
+        return CaretBlinkInterval::Disabled;
+    }
+    Duration::try_from_secs_f64(on + off)
+        .map(CaretBlinkInterval::Interval)
+        .unwrap_or(CaretBlinkInterval::NoPreference)
+}
+
+#[cfg(feature = "caret-blink-interval")]
+fn caret_blink_period_on_key() -> &'static NSString {
+    ns_string!("NSTextInsertionPointBlinkPeriodOn")
+}
+
+#[cfg(feature = "caret-blink-interval")]
+fn caret_blink_period_off_key() -> &'static NSString {
+    ns_string!("NSTextInsertionPointBlinkPeriodOff")
+}
+
+#[cfg(feature = "time-format")]
+fn get_time_format() -> TimeFormat {
+    // `NSDateFormatter`'s locale-aware "j" template resolves to a 12-hour format
+    // (containing an "a"/"b"/"B" AM/PM designator) or a 24-hour one, which is the
+    // closest thing Foundation exposes to a "clock format" preference.
+    let locale = unsafe { NSLocale::currentLocale() };
+    let template = NSString::from_str("j");
+    // SAFETY: `dateFormatFromTemplate:options:locale:` has no documented
+    // preconditions and doesn't take any raw pointers.
+    let format = unsafe {
+        NSDateFormatter::dateFormatFromTemplate_options_locale(&template, 0, Some(&locale))
+    };
+    match format {
+        Some(format) if format.to_string().contains(['a', 'b', 'B']) => TimeFormat::Twelve,
+        Some(_) => TimeFormat::TwentyFour,
+        None => TimeFormat::NoPreference,
+    }
+}
+
+#[cfg(feature = "ui-scale-factor")]
+fn get_ui_scale_factor() -> UiScaleFactor {
+    // There's no "the" screen, but `mainScreen` (the one with the focused window, or
+    // the one holding the menu bar if no window is focused) is the closest thing to a
+    // system-wide scale factor that AppKit exposes.
+    let scale_factor = unsafe { NSScreen::mainScreen() }.map(|screen| unsafe {
+        // We have to cast because on 32-bit platforms, `CGFloat` = f32.
+        screen.backingScaleFactor() as f64
+    });
+    UiScaleFactor(scale_factor)
+}
+
+// Subpixel (ClearType-style) antialiasing was removed from AppKit's text rendering
+// starting with macOS Mojave, so `AppleFontSmoothing` only still toggles grayscale
+// antialiasing on or off; there's no hinting level or subpixel order to read either.
+#[cfg(feature = "font-rendering")]
+fn get_font_rendering() -> FontRendering {
+    let defaults = unsafe { NSUserDefaults::standardUserDefaults() };
+    let key = apple_font_smoothing_key();
+    // `integerForKey:` returns `0` both for an explicit "off" and for the key never
+    // having been set, so check for its presence via `objectForKey:` first.
+    let antialiasing = if unsafe { defaults.objectForKey(key) }.is_none() {
+        Antialiasing::NoPreference
+    } else if unsafe { defaults.integerForKey(key) } == 0 {
+        Antialiasing::None
+    } else {
+        Antialiasing::Grayscale
+    };
+    FontRendering {
+        antialiasing,
+        hinting: Hinting::NoPreference,
+        subpixel_order: SubpixelOrder::NoPreference,
+    }
+}
+
+#[cfg(feature = "font-rendering")]
+fn apple_font_smoothing_key() -> &'static NSString {
+    ns_string!("AppleFontSmoothing")
+}
+
+// `canRepresentDisplayGamut:` only distinguishes sRGB from P3, AppKit has no
+// `NSDisplayGamut` variant for Rec. 2020, so the widest we can ever report here is P3.
+#[cfg(feature = "color-gamut")]
+fn get_color_gamut() -> ColorGamut {
+    let Some(screen) = (unsafe { NSScreen::mainScreen() }) else {
+        return ColorGamut::NoPreference;
+    };
+    if unsafe { screen.canRepresentDisplayGamut(NSDisplayGamut::P3) } {
+        ColorGamut::P3
+    } else if unsafe { screen.canRepresentDisplayGamut(NSDisplayGamut::SRGB) } {
+        ColorGamut::Srgb
+    } else {
+        ColorGamut::NoPreference
+    }
+}
@@ -6,6 +6,12 @@ use super::get_contrast;
 use super::get_reduced_motion;
 #[cfg(feature = "reduced-transparency")]
 use super::get_reduced_transparency;
+#[cfg(feature = "inverted-colors")]
+use super::get_inverted_colors;
+#[cfg(feature = "system-colors")]
+use super::get_system_colors;
+#[cfg(feature = "caret-blink-interval")]
+use super::{caret_blink_period_off_key, caret_blink_period_on_key, get_caret_blink_interval};
 #[cfg(feature = "color-scheme")]
 use super::main_thread::run_on_main_async;
 #[cfg(feature = "_macos-accessibility")]
@@ -13,10 +19,14 @@ use super::preference::AccessibilityPreferences;
 use super::preference::Preference;
 #[cfg(feature = "color-scheme")]
 use super::to_color_scheme;
+#[cfg(feature = "ui-scale-factor")]
+use super::get_ui_scale_factor;
 use crate::Interest;
+#[cfg(feature = "accent-color")]
+use block2::RcBlock;
 use futures_channel::mpsc;
 use objc2::rc::Retained;
-#[cfg(feature = "color-scheme")]
+#[cfg(any(feature = "color-scheme", feature = "caret-blink-interval"))]
 use objc2::runtime::AnyObject;
 #[cfg(any(feature = "accent-color", feature = "_macos-accessibility"))]
 use objc2::sel;
@@ -24,28 +34,46 @@ use objc2::{define_class, msg_send, AllocAnyThread as _, DeclaredClass};
 #[cfg(feature = "color-scheme")]
 use objc2_app_kit::NSAppearance;
 use objc2_app_kit::NSApplication;
-#[cfg(feature = "accent-color")]
+#[cfg(any(feature = "accent-color", feature = "system-colors"))]
 use objc2_app_kit::NSSystemColorsDidChangeNotification;
+#[cfg(feature = "ui-scale-factor")]
+use objc2_app_kit::NSApplicationDidChangeScreenParametersNotification;
 #[cfg(feature = "_macos-accessibility")]
 use objc2_app_kit::NSWorkspace;
 #[cfg(feature = "_macos-accessibility")]
 use objc2_app_kit::NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification;
 #[cfg(feature = "accent-color")]
+use objc2_foundation::NSDistributedNotificationCenter;
+#[cfg(any(feature = "accent-color", feature = "system-colors"))]
 use objc2_foundation::NSNotificationCenter;
+#[cfg(feature = "accent-color")]
+use objc2_foundation::NSNotification;
 use objc2_foundation::NSObject;
-#[cfg(feature = "color-scheme")]
+#[cfg(any(
+    feature = "color-scheme",
+    feature = "caret-blink-interval",
+    feature = "accent-color"
+))]
+use objc2_foundation::ns_string;
+#[cfg(any(feature = "color-scheme", feature = "caret-blink-interval"))]
 use objc2_foundation::{
-    ns_string, NSDictionary, NSKeyValueChangeKey, NSKeyValueChangeNewKey,
-    NSKeyValueObservingOptions, NSObjectNSKeyValueObserverRegistration as _, NSString,
+    NSDictionary, NSKeyValueChangeKey, NSKeyValueChangeNewKey, NSKeyValueObservingOptions,
+    NSObjectNSKeyValueObserverRegistration as _, NSString,
 };
-#[cfg(feature = "color-scheme")]
+#[cfg(feature = "caret-blink-interval")]
+use objc2_foundation::NSUserDefaults;
+#[cfg(any(feature = "color-scheme", feature = "caret-blink-interval"))]
 use std::ffi::c_void;
-#[cfg(feature = "color-scheme")]
+#[cfg(feature = "accent-color")]
+use std::ptr::NonNull;
+#[cfg(any(feature = "color-scheme", feature = "caret-blink-interval"))]
 use std::ptr;
 
 pub(crate) struct ObserverRegistration {
     observer: Retained<Observer>,
     interest: Interest,
+    #[cfg(feature = "accent-color")]
+    accent_color_distributed_observer: Option<Retained<NSObject>>,
 }
 
 #[cfg(feature = "color-scheme")]
@@ -53,6 +81,30 @@ fn effective_appearance_key() -> &'static NSString {
     ns_string!("effectiveAppearance")
 }
 
+// `NSSystemColorsDidChangeNotification` (used for `AccentColor` below) is occasionally
+// slow to reflect a just-changed accent color, so we additionally watch the distributed
+// (not workspace/default) `AppleColorPreferencesChangedNotification`, which System
+// Settings posts immediately after writing the new accent color to defaults.
+#[cfg(feature = "accent-color")]
+fn register_accent_color_distributed_observer(
+    sender: mpsc::UnboundedSender<Preference>,
+) -> Retained<NSObject> {
+    let center = unsafe { NSDistributedNotificationCenter::defaultCenter() };
+    let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+        _ = sender.unbounded_send(Preference::AccentColor(get_accent_color()));
+    });
+    // SAFETY: `addObserverForName:object:queue:usingBlock:` copies the block, so it
+    // doesn't need to outlive this call. The returned token is removed on drop.
+    unsafe {
+        center.addObserverForName_object_queue_usingBlock(
+            Some(ns_string!("AppleColorPreferencesChangedNotification")),
+            None,
+            None,
+            &block,
+        )
+    }
+}
+
 impl Observer {
     pub(crate) fn register(
         #[cfg_attr(not(feature = "color-scheme"), expect(unused_variables))]
@@ -60,6 +112,13 @@ impl Observer {
         sender: mpsc::UnboundedSender<Preference>,
         interest: Interest,
     ) -> ObserverRegistration {
+        #[cfg(feature = "accent-color")]
+        let accent_color_distributed_observer = if interest.is(Interest::AccentColor) {
+            Some(register_accent_color_distributed_observer(sender.clone()))
+        } else {
+            None
+        };
+
         let observer = Self::new(sender);
 
         #[cfg(feature = "color-scheme")]
@@ -94,10 +153,10 @@ impl Observer {
         if interest.is(Interest::AccentColor) {
             // SAFETY: The observer is removed on drop.
             unsafe {
-                // We're reacting to `NSSystemColorsDidChangeNotification` instead of the sometimes
-                // used "AppleColorPreferencesChangedNotification" for two reasons:
-                // * The former is officially documented while the latter is not.
-                // * When reacting to the latter, `NSColor::controlAccentColor()` is sometimes not updated yet.
+                // `NSSystemColorsDidChangeNotification` is the officially documented
+                // signal for this; `register_accent_color_distributed_observer` above
+                // additionally watches the undocumented-but-faster distributed
+                // notification, so the two together cover both reliability and latency.
                 let notification_center = NSNotificationCenter::defaultCenter();
                 notification_center.addObserver_selector_name_object(
                     &observer,
@@ -108,7 +167,62 @@ impl Observer {
             }
         }
 
-        ObserverRegistration { observer, interest }
+        #[cfg(feature = "system-colors")]
+        if interest.is(Interest::SystemColors) {
+            // SAFETY: The observer is removed on drop.
+            unsafe {
+                // Same notification as `AccentColor` above, just routed to a different
+                // selector since the two preferences are independently requestable.
+                let notification_center = NSNotificationCenter::defaultCenter();
+                notification_center.addObserver_selector_name_object(
+                    &observer,
+                    sel!(systemPaletteDidChange),
+                    Some(NSSystemColorsDidChangeNotification),
+                    None,
+                );
+            }
+        }
+
+        #[cfg(feature = "caret-blink-interval")]
+        if interest.is(Interest::CaretBlinkInterval) {
+            // SAFETY: The observer is removed on drop.
+            unsafe {
+                let defaults = NSUserDefaults::standardUserDefaults();
+                defaults.addObserver_forKeyPath_options_context(
+                    &observer,
+                    caret_blink_period_on_key(),
+                    NSKeyValueObservingOptions::New,
+                    ptr::null_mut(),
+                );
+                defaults.addObserver_forKeyPath_options_context(
+                    &observer,
+                    caret_blink_period_off_key(),
+                    NSKeyValueObservingOptions::New,
+                    ptr::null_mut(),
+                );
+            }
+        }
+
+        #[cfg(feature = "ui-scale-factor")]
+        if interest.is(Interest::UiScaleFactor) {
+            // SAFETY: The observer is removed on drop.
+            unsafe {
+                let notification_center = NSNotificationCenter::defaultCenter();
+                notification_center.addObserver_selector_name_object(
+                    &observer,
+                    sel!(uiScaleFactorDidChange),
+                    Some(NSApplicationDidChangeScreenParametersNotification),
+                    None,
+                );
+            }
+        }
+
+        ObserverRegistration {
+            observer,
+            interest,
+            #[cfg(feature = "accent-color")]
+            accent_color_distributed_observer,
+        }
     }
 
     fn new(sender: mpsc::UnboundedSender<Preference>) -> Retained<Observer> {
@@ -148,6 +262,37 @@ impl Drop for ObserverRegistration {
                 let notification_center = NSNotificationCenter::defaultCenter();
                 notification_center.removeObserver(&self.observer);
             }
+            if let Some(token) = &self.accent_color_distributed_observer {
+                unsafe {
+                    let notification_center = NSDistributedNotificationCenter::defaultCenter();
+                    notification_center.removeObserver(token);
+                }
+            }
+        }
+
+        #[cfg(feature = "system-colors")]
+        if self.interest.is(Interest::SystemColors) {
+            unsafe {
+                let notification_center = NSNotificationCenter::defaultCenter();
+                notification_center.removeObserver(&self.observer);
+            }
+        }
+
+        #[cfg(feature = "caret-blink-interval")]
+        if self.interest.is(Interest::CaretBlinkInterval) {
+            unsafe {
+                let defaults = NSUserDefaults::standardUserDefaults();
+                defaults.removeObserver_forKeyPath(&self.observer, caret_blink_period_on_key());
+                defaults.removeObserver_forKeyPath(&self.observer, caret_blink_period_off_key());
+            }
+        }
+
+        #[cfg(feature = "ui-scale-factor")]
+        if self.interest.is(Interest::UiScaleFactor) {
+            unsafe {
+                let notification_center = NSNotificationCenter::defaultCenter();
+                notification_center.removeObserver(&self.observer);
+            }
         }
     }
 }
@@ -168,6 +313,23 @@ define_class! {
             _ = self.ivars().sender.unbounded_send(Preference::AccentColor(get_accent_color()));
         }
 
+        #[cfg(feature = "system-colors")]
+        #[unsafe(method(systemPaletteDidChange))]
+        fn system_palette_did_change(&self) {
+            _ = self.ivars().sender.unbounded_send(Preference::SystemColors(get_system_colors()));
+        }
+
+        #[cfg(feature = "ui-scale-factor")]
+        #[unsafe(method(uiScaleFactorDidChange))]
+        fn ui_scale_factor_did_change(&self) {
+            _ = self.ivars().sender.unbounded_send(Preference::UiScaleFactor(get_ui_scale_factor()));
+        }
+
+        // A single `NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification` fires for
+        // changes to any of contrast/reduced-motion/reduced-transparency/inverted-colors,
+        // so re-read every one of them that's compiled in here (regardless of which ones
+        // this subscription is interested in) rather than trying to infer which one
+        // actually changed.
         #[cfg(feature = "_macos-accessibility")]
         #[unsafe(method(accessibilityDisplayOptionsDidChange))]
         fn accessibility_options_did_change(&self) {
@@ -184,25 +346,36 @@ define_class! {
             {
                 prefs.reduced_transparency = get_reduced_transparency();
             }
+            #[cfg(feature = "inverted-colors")]
+            {
+                prefs.inverted_colors = get_inverted_colors();
+            }
             _ = self.ivars().sender.unbounded_send(Preference::Accessibility(prefs));
         }
 
-        #[cfg(feature = "color-scheme")]
+        #[cfg(any(feature = "color-scheme", feature = "caret-blink-interval"))]
         #[unsafe(method(observeValueForKeyPath:ofObject:change:context:))]
         fn observe_value(
             &self,
             key_path: Option<&NSString>,
             _object: Option<&AnyObject>,
+            #[cfg_attr(not(feature = "color-scheme"), expect(unused_variables))]
             change: Option<&NSDictionary<NSKeyValueChangeKey, AnyObject>>,
             _context: *mut c_void,
         )
         {
+            #[cfg(feature = "color-scheme")]
             if key_path == Some(effective_appearance_key()) {
                 let change = change.expect("requested a change dictionary in `addObserver`, but none was provided");
                 let new = change.objectForKey(unsafe { NSKeyValueChangeNewKey }).expect("requested change dictionary did not contain `NSKeyValueChangeNewKey`");
                 let new: &NSAppearance = new.downcast_ref().expect("effectiveAppearance is NSAppearance");
                 _ = self.ivars().sender.unbounded_send(Preference::ColorScheme(to_color_scheme(new)));
             }
+
+            #[cfg(feature = "caret-blink-interval")]
+            if key_path == Some(caret_blink_period_on_key()) || key_path == Some(caret_blink_period_off_key()) {
+                _ = self.ivars().sender.unbounded_send(Preference::CaretBlinkInterval(get_caret_blink_interval()));
+            }
         }
     }
 }
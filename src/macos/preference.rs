@@ -9,6 +9,14 @@ use crate::Contrast;
 use crate::ReducedMotion;
 #[cfg(feature = "reduced-transparency")]
 use crate::ReducedTransparency;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "system-colors")]
+use crate::SystemColors;
+#[cfg(feature = "caret-blink-interval")]
+use crate::CaretBlinkInterval;
+#[cfg(feature = "ui-scale-factor")]
+use crate::UiScaleFactor;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Preference {
@@ -18,6 +26,12 @@ pub(crate) enum Preference {
     Accessibility(AccessibilityPreferences),
     #[cfg(feature = "accent-color")]
     AccentColor(AccentColor),
+    #[cfg(feature = "system-colors")]
+    SystemColors(SystemColors),
+    #[cfg(feature = "caret-blink-interval")]
+    CaretBlinkInterval(CaretBlinkInterval),
+    #[cfg(feature = "ui-scale-factor")]
+    UiScaleFactor(UiScaleFactor),
 }
 
 #[cfg(feature = "_macos-accessibility")]
@@ -29,6 +43,8 @@ pub(crate) struct AccessibilityPreferences {
     pub(crate) reduced_motion: ReducedMotion,
     #[cfg(feature = "reduced-transparency")]
     pub(crate) reduced_transparency: ReducedTransparency,
+    #[cfg(feature = "inverted-colors")]
+    pub(crate) inverted_colors: InvertedColors,
 }
 
 impl Preference {
@@ -50,9 +66,19 @@ impl Preference {
                 {
                     preferences.reduced_transparency = p.reduced_transparency;
                 }
+                #[cfg(feature = "inverted-colors")]
+                {
+                    preferences.inverted_colors = p.inverted_colors;
+                }
             }
             #[cfg(feature = "accent-color")]
             Preference::AccentColor(v) => preferences.accent_color = v,
+            #[cfg(feature = "system-colors")]
+            Preference::SystemColors(v) => preferences.system_colors = v,
+            #[cfg(feature = "caret-blink-interval")]
+            Preference::CaretBlinkInterval(v) => preferences.caret_blink_interval = v,
+            #[cfg(feature = "ui-scale-factor")]
+            Preference::UiScaleFactor(v) => preferences.ui_scale_factor = v,
         };
         preferences
     }
@@ -0,0 +1,98 @@
+use futures_lite::stream::Stream;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+pin_project! {
+    /// Collapses bursts of rapid items into a single emission: holds the most
+    /// recent item from `stream` and only yields it once the stream has been
+    /// quiet for `duration`, resetting the timer on every new item and keeping
+    /// the latest value rather than the first.
+    ///
+    /// Stream end is forwarded immediately, even if an item is still pending.
+    pub struct Debounce<S: Stream> {
+        #[pin]
+        stream: S,
+        duration: Duration,
+        pending: Option<<S as Stream>::Item>,
+        deadline: Option<Instant>,
+        // Whether a timer thread is already outstanding for `deadline`, so a
+        // spuriously re-polled `Pending` doesn't spawn another one on top of it.
+        timer_armed: bool,
+    }
+}
+
+impl<S: Stream> Debounce<S> {
+    pub(crate) fn new(stream: S, duration: Duration) -> Self {
+        Debounce {
+            stream,
+            duration,
+            pending: None,
+            deadline: None,
+            timer_armed: false,
+        }
+    }
+}
+
+impl<S> Stream for Debounce<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.pending = Some(item);
+                    *this.deadline = Some(Instant::now() + *this.duration);
+                    *this.timer_armed = false;
+                    // Keep draining so a burst of immediately-ready items
+                    // collapses down to just the latest one.
+                    continue;
+                }
+                // Forwarded immediately rather than waiting out whatever
+                // debounce window is still pending.
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => break,
+            }
+        }
+
+        let Some(deadline) = *this.deadline else {
+            return Poll::Pending;
+        };
+
+        let now = Instant::now();
+        if now >= deadline {
+            *this.deadline = None;
+            *this.timer_armed = false;
+            return Poll::Ready(this.pending.take());
+        }
+
+        if !*this.timer_armed {
+            *this.timer_armed = wake_after(deadline - now, cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+// There's no portable timer primitive shared by every async runtime this crate
+// might be driven by, so we just spawn a one-shot thread that sleeps for the
+// remaining debounce window and then wakes us back up. Returns whether the
+// timer was armed; if spawning fails, we just leave it unarmed rather than
+// panicking the polling task, and try again next time we're polled.
+fn wake_after(delay: Duration, waker: Waker) -> bool {
+    let spawned = std::thread::Builder::new()
+        .name(format!("{} debounce timer", env!("CARGO_PKG_NAME")))
+        .spawn(move || {
+            std::thread::sleep(delay);
+            waker.wake();
+        });
+    if let Err(err) = &spawned {
+        log::warn!("failed to spawn debounce timer thread: {err}");
+    }
+    spawned.is_ok()
+}
@@ -16,7 +16,8 @@ pub(crate) use scan::*;
 mod dedup;
 pub(crate) use dedup::*;
 
-#[cfg(target_os = "linux")]
+mod debounce;
+pub(crate) use debounce::*;
+
 mod either;
-#[cfg(target_os = "linux")]
 pub(crate) use either::*;
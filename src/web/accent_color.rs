@@ -4,7 +4,7 @@ use std::ops::Deref;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{css, Comment, HtmlElement, TransitionEvent, Window};
 
-type JsResult<T> = Result<T, JsValue>;
+pub(crate) type JsResult<T> = Result<T, JsValue>;
 
 /// Detects the accent color by creating an element with `color: AccentColor`
 // spellchecker:off
@@ -35,7 +35,7 @@ impl AccentColorObserver {
     }
 }
 
-fn supports_accent_color() -> bool {
+pub(crate) fn supports_accent_color() -> bool {
     css::supports("color: AccentColor").unwrap_or_default()
 }
 
@@ -67,7 +67,7 @@ fn create_element(window: &Window) -> Option<OffscreenElement> {
 // Note that the element has to be attached to the DOM
 // and "visible" (not hidden via the `hidden` attribute or similar)
 // for transitions to happen.
-fn add_color_change_listener(
+pub(crate) fn add_color_change_listener(
     element: &HtmlElement,
     mut f: impl FnMut() + 'static,
 ) -> JsResult<EventListenerGuard> {
@@ -81,10 +81,13 @@ fn add_color_change_listener(
 }
 
 fn get_accent_color_from_computed_style(window: &Window, element: &HtmlElement) -> AccentColor {
-    AccentColor(get_color_from_computed_style(window, element))
+    AccentColor(get_color_from_computed_style(window, element).map(Into::into))
 }
 
-fn get_color_from_computed_style(window: &Window, element: &HtmlElement) -> Option<Srgba> {
+pub(crate) fn get_color_from_computed_style(
+    window: &Window,
+    element: &HtmlElement,
+) -> Option<Srgba> {
     let style = window.get_computed_style(element).ok().flatten()?;
     let value = style.get_property_value("color").ok()?;
     parse_css_color_value(&value)
@@ -92,10 +95,10 @@ fn get_color_from_computed_style(window: &Window, element: &HtmlElement) -> Opti
 
 /// An offscreeen and inert HTML element that's removed on drop.
 #[derive(Clone)]
-struct OffscreenElement(HtmlElement);
+pub(crate) struct OffscreenElement(HtmlElement);
 
 impl OffscreenElement {
-    fn new(window: &Window, description: &str) -> Option<Self> {
+    pub(crate) fn new(window: &Window, description: &str) -> Option<Self> {
         let document = window.document()?;
         let body = document.body()?;
         let element: HtmlElement = document.create_element("div").ok()?.unchecked_into();
@@ -130,49 +133,272 @@ impl Drop for OffscreenElement {
     }
 }
 
+// `getComputedStyle` can hand back any of these serializations depending on
+// the browser and on the gamut of the underlying color, so rather than
+// hand-rolling a parser per form, we tokenize: strip the outer `#`/`rgb(...)`/
+// `rgba(...)`/`color(...)` wrapper, then split the inside on whichever
+// separator it actually uses.
+//
 // Some excerpts from <https://www.w3.org/TR/css-color-4/#serializing-color-values>
 // are sprinkled throughout this code for clarity.
 fn parse_css_color_value(s: &str) -> Option<Srgba> {
-    // > [..] For compatibility, the legacy form with comma separators is used; exactly one ASCII space follows each comma.
-    // > This includes the comma (not slash) used to separate the blue component of rgba() from the alpha value.
-    const SEPARATOR: &str = ", ";
-
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(inner) = s.strip_prefix("color(").and_then(|s| s.strip_suffix(')')) {
+        return parse_color_function(inner);
+    }
     // > [..] Also, for compatibility, if the alpha is exactly 1, the rgb() form is used,
     // with an implicit alpha; otherwise, the rgba() form is used, with an explicit alpha value.
-    if let Some(parts) = rgb(s) {
-        let mut parts = parts.splitn(3, SEPARATOR);
-        Some(Srgba::from_f64_array([
-            component(&mut parts)? / 255.,
-            component(&mut parts)? / 255.,
-            component(&mut parts)? / 255.,
-            1.,
-        ]))
-    } else if let Some(parts) = rgba(s) {
-        let mut parts = parts.splitn(4, SEPARATOR);
-        Some(Srgba::from_f64_array([
-            component(&mut parts)? / 255.,
-            component(&mut parts)? / 255.,
-            component(&mut parts)? / 255.,
-            component(&mut parts)?,
-        ]))
-    } else {
-        None
-    }
+    let inner = s
+        .strip_prefix("rgba(")
+        .or_else(|| s.strip_prefix("rgb("))
+        .and_then(|s| s.strip_suffix(')'))?;
+    parse_rgb_components(inner)
 }
 
-fn rgb(s: &str) -> Option<&str> {
-    s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(")"))
+// > [..] For compatibility, the legacy form with comma separators is used; exactly one ASCII space follows each comma.
+// > This includes the comma (not slash) used to separate the blue component of rgba() from the alpha value.
+//
+// Modern browsers may also serialize using the space-separated `rgb(r g b / a)` form,
+// so we detect which separator is in use rather than assuming commas.
+fn parse_rgb_components(inner: &str) -> Option<Srgba> {
+    let (rgb, slash_alpha) = match inner.split_once('/') {
+        Some((rgb, alpha)) => (rgb, Some(alpha)),
+        None => (inner, None),
+    };
+    let separator = if rgb.contains(',') { ',' } else { ' ' };
+    let mut components = rgb.split(separator).map(str::trim).filter(|s| !s.is_empty());
+    let red = rgb_component(components.next()?)?;
+    let green = rgb_component(components.next()?)?;
+    let blue = rgb_component(components.next()?)?;
+    let alpha = match slash_alpha.or(components.next()) {
+        Some(alpha) => alpha_component(alpha.trim())?,
+        None => 1.,
+    };
+    Some(clamp(Srgba::from_f64_array([red, green, blue, alpha])))
 }
 
-fn rgba(s: &str) -> Option<&str> {
-    s.strip_prefix("rgba(").and_then(|s| s.strip_suffix(")"))
+// `color(<colorspace> c1 c2 c3 [/ alpha])`, used by browsers to report colors outside
+// of the sRGB gamut. We only need to handle the colorspaces that `AccentColor` could
+// plausibly resolve to: `srgb` needs no conversion, while `srgb-linear` and
+// `display-p3` need to be mapped back into (possibly out-of-gamut, hence the clamp) sRGB.
+fn parse_color_function(inner: &str) -> Option<Srgba> {
+    let (channels, slash_alpha) = match inner.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha)),
+        None => (inner, None),
+    };
+    let mut parts = channels.split_whitespace();
+    let colorspace = parts.next()?;
+    let c1 = color_component(parts.next()?)?;
+    let c2 = color_component(parts.next()?)?;
+    let c3 = color_component(parts.next()?)?;
+    let alpha = match slash_alpha {
+        Some(alpha) => alpha_component(alpha.trim())?,
+        None => 1.,
+    };
+    let [red, green, blue] = match colorspace {
+        "srgb" => [c1, c2, c3],
+        "srgb-linear" => [srgb_oetf(c1), srgb_oetf(c2), srgb_oetf(c3)],
+        "display-p3" => display_p3_to_srgb([c1, c2, c3]),
+        _ => return None,
+    };
+    Some(clamp(Srgba::from_f64_array([red, green, blue, alpha])))
 }
 
+fn parse_hex(hex: &str) -> Option<Srgba> {
+    let digit = |c: char| c.to_digit(16).map(|v| v as u8);
+    match hex.len() {
+        3 => {
+            let mut digits = hex.chars().map(digit);
+            let [r, g, b] = [digits.next()??, digits.next()??, digits.next()??];
+            Some(Srgba::from_u8_array([r * 17, g * 17, b * 17, 255]))
+        }
+        6 | 8 => {
+            let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            let (r, g, b) = (byte(0)?, byte(2)?, byte(4)?);
+            let a = if hex.len() == 8 { byte(6)? } else { 255 };
+            Some(Srgba::from_u8_array([r, g, b, a]))
+        }
+        _ => None,
+    }
+}
+
+// A component of the legacy `rgb()`/`rgba()` forms is either an `<integer>` in `[0, 255]`
+// or a `<percentage>` relative to that range.
+//
 // > [..] authors of scripts which expect color values returned from getComputedStyle to have <integer> component values,
 // > are advised to update them to also cope with <number>.
-fn component<'a>(parts: &mut impl Iterator<Item = &'a str>) -> Option<f64> {
-    let value = parts.next()?;
-    value.parse().ok()
+fn rgb_component(token: &str) -> Option<f64> {
+    match token.strip_suffix('%') {
+        Some(percentage) => Some(percentage.trim().parse::<f64>().ok()? / 100.),
+        None => Some(token.parse::<f64>().ok()? / 255.),
+    }
+}
+
+// A component of the `color()` function is already normalized to `[0, 1]`,
+// with percentages still relative to that same range.
+fn color_component(token: &str) -> Option<f64> {
+    match token.strip_suffix('%') {
+        Some(percentage) => Some(percentage.trim().parse::<f64>().ok()? / 100.),
+        None => token.parse().ok(),
+    }
+}
+
+fn alpha_component(token: &str) -> Option<f64> {
+    match token.strip_suffix('%') {
+        Some(percentage) => Some(percentage.trim().parse::<f64>().ok()? / 100.),
+        None => token.parse().ok(),
+    }
+}
+
+fn clamp(color: Srgba) -> Srgba {
+    Srgba::from_f64_array(color.to_f64_array().map(|c| c.clamp(0., 1.)))
+}
+
+// The sRGB electro-optical transfer function (gamma decode), i.e. nonlinear -> linear.
+fn srgb_eotf(c: f64) -> f64 {
+    if c.abs() <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// The inverse of [`srgb_eotf`] (gamma encode), i.e. linear -> nonlinear.
+fn srgb_oetf(c: f64) -> f64 {
+    if c.abs() <= 0.0031308 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * c.abs().powf(1. / 2.4) - 0.055)
+    }
+}
+
+// Display P3 shares sRGB's transfer function and D65 white point, but uses wider
+// primaries, so the conversion is: decode P3's gamma, apply the fixed linear-light
+// P3-to-sRGB matrix below, then re-encode sRGB's gamma.
+//
+// Matrix taken from the reference conversion code in
+// <https://www.w3.org/TR/css-color-4/#color-conversion-code>.
+fn display_p3_to_srgb([r, g, b]: [f64; 3]) -> [f64; 3] {
+    const P3_TO_SRGB: [[f64; 3]; 3] = [
+        [1.2249401762, -0.2249401762, 0.0000000000],
+        [-0.0420569547, 1.0420569547, 0.0000000000],
+        [-0.0196375546, -0.0786360455, 1.0982736021],
+    ];
+    let linear = [srgb_eotf(r), srgb_eotf(g), srgb_eotf(b)];
+    P3_TO_SRGB.map(|row| srgb_oetf(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]))
 }
 
-// TODO: unit tests for the parser
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_color_eq(actual: Option<Srgba>, expected: [u8; 4]) {
+        let actual = actual.expect("color should have parsed").to_u8_array();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_legacy_comma_rgb() {
+        assert_color_eq(parse_css_color_value("rgb(255, 0, 0)"), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn parses_legacy_comma_rgba() {
+        assert_color_eq(
+            parse_css_color_value("rgba(0, 128, 255, 0.5)"),
+            [0, 128, 255, 128],
+        );
+    }
+
+    #[test]
+    fn parses_modern_space_rgb() {
+        assert_color_eq(parse_css_color_value("rgb(0 255 0)"), [0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn parses_modern_space_rgb_with_alpha() {
+        assert_color_eq(
+            parse_css_color_value("rgb(0 255 0 / 0.5)"),
+            [0, 255, 0, 128],
+        );
+    }
+
+    #[test]
+    fn parses_percentage_components() {
+        assert_color_eq(
+            parse_css_color_value("rgb(100% 0% 0% / 50%)"),
+            [255, 0, 0, 128],
+        );
+    }
+
+    #[test]
+    fn parses_short_hex() {
+        assert_color_eq(parse_css_color_value("#f00"), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn parses_long_hex() {
+        assert_color_eq(parse_css_color_value("#0080ff"), [0, 128, 255, 255]);
+    }
+
+    #[test]
+    fn parses_long_hex_with_alpha() {
+        assert_color_eq(parse_css_color_value("#0080ff80"), [0, 128, 255, 128]);
+    }
+
+    #[test]
+    fn parses_color_function_srgb() {
+        assert_color_eq(
+            parse_css_color_value("color(srgb 1 0 0)"),
+            [255, 0, 0, 255],
+        );
+    }
+
+    #[test]
+    fn parses_color_function_srgb_with_alpha() {
+        assert_color_eq(
+            parse_css_color_value("color(srgb 0 0.5019608 1 / 0.5)"),
+            [0, 128, 255, 128],
+        );
+    }
+
+    #[test]
+    fn parses_color_function_srgb_linear() {
+        // Linear-light 0.5 encodes to ~0.7354 in gamma-encoded sRGB.
+        let color = parse_css_color_value("color(srgb-linear 0.5 0.5 0.5)")
+            .expect("color should have parsed");
+        assert_eq!(color.to_u8_array(), [188, 188, 188, 255]);
+    }
+
+    #[test]
+    fn parses_color_function_display_p3_primary() {
+        // Display P3's red primary is outside the sRGB gamut, so after conversion
+        // it should clamp to pure red rather than going negative on green/blue.
+        assert_color_eq(
+            parse_css_color_value("color(display-p3 1 0 0)"),
+            [255, 0, 0, 255],
+        );
+    }
+
+    #[test]
+    fn parses_color_function_display_p3_gray_roundtrips() {
+        // A gray value has the same coordinates in both color spaces.
+        assert_color_eq(
+            parse_css_color_value("color(display-p3 0.5 0.5 0.5)"),
+            [128, 128, 128, 255],
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_color_function_colorspace() {
+        assert_eq!(parse_css_color_value("color(xyz 0.5 0.5 0.5)"), None);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_css_color_value("not-a-color"), None);
+    }
+}
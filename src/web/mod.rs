@@ -1,5 +1,7 @@
 #[cfg(feature = "accent-color")]
 use crate::AccentColor;
+#[cfg(feature = "color-gamut")]
+use crate::ColorGamut;
 #[cfg(feature = "color-scheme")]
 use crate::ColorScheme;
 #[cfg(feature = "contrast")]
@@ -8,6 +10,18 @@ use crate::Contrast;
 use crate::ReducedMotion;
 #[cfg(feature = "reduced-transparency")]
 use crate::ReducedTransparency;
+#[cfg(feature = "forced-colors")]
+use crate::ForcedColors;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "reduced-data")]
+use crate::ReducedData;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
+#[cfg(feature = "ui-scale-factor")]
+use crate::UiScaleFactor;
+#[cfg(feature = "system-palette")]
+use crate::SystemPalette;
 
 use crate::stream_utils::Scan;
 use crate::{AvailablePreferences, Interest};
@@ -21,13 +35,27 @@ use web_sys::window;
 
 #[cfg(feature = "accent-color")]
 mod accent_color;
-#[cfg(any(feature = "reduced-motion", feature = "reduced-transparency"))]
+#[cfg(feature = "reduced-motion")]
 mod bool;
 mod event_listener;
-#[cfg(any(feature = "contrast", feature = "color-scheme"))]
+#[cfg(any(
+    feature = "contrast",
+    feature = "color-scheme",
+    feature = "color-gamut",
+    feature = "reduced-transparency",
+    feature = "forced-colors",
+    feature = "inverted-colors",
+    feature = "reduced-data"
+))]
 #[macro_use]
 mod multi_value;
 mod drop_on_main_thread;
+#[cfg(feature = "time-format")]
+mod time_format;
+#[cfg(feature = "ui-scale-factor")]
+mod ui_scale_factor;
+#[cfg(feature = "system-palette")]
+mod system_palette;
 
 #[cfg(feature = "accent-color")]
 type AccentColorObserver = Option<DropOnMainThread<accent_color::AccentColorObserver>>;
@@ -35,10 +63,24 @@ type AccentColorObserver = Option<DropOnMainThread<accent_color::AccentColorObse
 #[cfg(not(feature = "accent-color"))]
 type AccentColorObserver = ();
 
+#[cfg(feature = "ui-scale-factor")]
+type UiScaleFactorObserver = Option<ui_scale_factor::UiScaleFactorObserver>;
+
+#[cfg(not(feature = "ui-scale-factor"))]
+type UiScaleFactorObserver = ();
+
+#[cfg(feature = "system-palette")]
+type SystemPaletteObserver = Option<DropOnMainThread<system_palette::SystemPaletteObserver>>;
+
+#[cfg(not(feature = "system-palette"))]
+type SystemPaletteObserver = ();
+
 pin_project! {
     pub(crate) struct PreferencesStream {
         _guards: Vec<DropOnMainThread<EventListenerGuard>>,
         _accent_color: AccentColorObserver,
+        _ui_scale_factor: UiScaleFactorObserver,
+        _system_palette: SystemPaletteObserver,
         #[pin] inner: stream::Boxed<AvailablePreferences>,
     }
 }
@@ -61,6 +103,8 @@ pub(crate) fn stream(interest: Interest) -> PreferencesStream {
         return PreferencesStream {
             _guards: Vec::default(),
             _accent_color: AccentColorObserver::default(),
+            _ui_scale_factor: UiScaleFactorObserver::default(),
+            _system_palette: SystemPaletteObserver::default(),
             inner: stream::once(AvailablePreferences::default()).boxed(),
         };
     };
@@ -88,10 +132,10 @@ pub(crate) fn stream(interest: Interest) -> PreferencesStream {
         let sender = sender.clone();
         if let Some(query) = prefers_reduced_transparency_query(&window) {
             preferences.reduced_transparency = query.value();
-            if let Some(guard) = query
+            if let Some(guards_) = query
                 .subscribe(move |v| _ = sender.unbounded_send(Preference::ReducedTransparency(v)))
             {
-                guards.push(guard);
+                guards.extend(guards_);
             }
         }
     }
@@ -122,6 +166,69 @@ pub(crate) fn stream(interest: Interest) -> PreferencesStream {
         }
     }
 
+    #[cfg(feature = "color-gamut")]
+    if interest.is(Interest::ColorGamut) {
+        let sender = sender.clone();
+        if let Some(query) = color_gamut_media_query(&window) {
+            preferences.color_gamut = query.value();
+            if let Some(guards_) =
+                query.subscribe(move |v| _ = sender.unbounded_send(Preference::ColorGamut(v)))
+            {
+                guards.extend(guards_);
+            }
+        }
+    }
+
+    #[cfg(feature = "forced-colors")]
+    if interest.is(Interest::ForcedColors) {
+        let sender = sender.clone();
+        if let Some(query) = forced_colors_media_query(&window) {
+            preferences.forced_colors = query.value();
+            if let Some(guards_) =
+                query.subscribe(move |v| _ = sender.unbounded_send(Preference::ForcedColors(v)))
+            {
+                guards.extend(guards_);
+            }
+        }
+    }
+
+    #[cfg(feature = "inverted-colors")]
+    if interest.is(Interest::InvertedColors) {
+        let sender = sender.clone();
+        if let Some(query) = inverted_colors_media_query(&window) {
+            preferences.inverted_colors = query.value();
+            if let Some(guards_) =
+                query.subscribe(move |v| _ = sender.unbounded_send(Preference::InvertedColors(v)))
+            {
+                guards.extend(guards_);
+            }
+        }
+    }
+
+    #[cfg(feature = "reduced-data")]
+    if interest.is(Interest::ReducedData) {
+        let sender = sender.clone();
+        if let Some(query) = reduced_data_media_query(&window) {
+            preferences.reduced_data = query.value();
+            if let Some(guards_) =
+                query.subscribe(move |v| _ = sender.unbounded_send(Preference::ReducedData(v)))
+            {
+                guards.extend(guards_);
+            }
+        }
+    }
+
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        let sender = sender.clone();
+        preferences.time_format = time_format::get_time_format();
+        if let Some(guard) = time_format::subscribe(&window, move |v| {
+            _ = sender.unbounded_send(Preference::TimeFormat(v))
+        }) {
+            guards.push(guard);
+        }
+    }
+
     #[cfg(feature = "accent-color")]
     let accent_color = if interest.is(Interest::AccentColor) {
         let sender = sender.clone();
@@ -136,12 +243,51 @@ pub(crate) fn stream(interest: Interest) -> PreferencesStream {
         None
     };
 
+    #[cfg(feature = "ui-scale-factor")]
+    let ui_scale_factor = if interest.is(Interest::UiScaleFactor) {
+        let sender = sender.clone();
+        let callback = move |v| _ = sender.unbounded_send(Preference::UiScaleFactor(v));
+        if let Some((observer, value)) = ui_scale_factor::UiScaleFactorObserver::new(&window, callback)
+        {
+            preferences.ui_scale_factor = value;
+            Some(observer)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    #[cfg(feature = "system-palette")]
+    let system_palette = if interest.is(Interest::SystemPalette) {
+        let sender = sender.clone();
+        let callback = move |v| _ = sender.unbounded_send(Preference::SystemPalette(v));
+        if let Some((observer, value)) =
+            system_palette::SystemPaletteObserver::new(&window, callback)
+        {
+            preferences.system_palette = value;
+            Some(DropOnMainThread::new(observer, &window))
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
     PreferencesStream {
         _guards: guards,
         #[cfg(feature = "accent-color")]
         _accent_color: accent_color,
         #[cfg(not(feature = "accent-color"))]
         _accent_color: (),
+        #[cfg(feature = "ui-scale-factor")]
+        _ui_scale_factor: ui_scale_factor,
+        #[cfg(not(feature = "ui-scale-factor"))]
+        _ui_scale_factor: (),
+        #[cfg(feature = "system-palette")]
+        _system_palette: system_palette,
+        #[cfg(not(feature = "system-palette"))]
+        _system_palette: (),
         inner: stream::once(preferences)
             .chain(changes(preferences, receiver))
             .boxed(),
@@ -188,6 +334,13 @@ pub(crate) fn once_blocking(
         }
     }
 
+    #[cfg(feature = "color-gamut")]
+    if interest.is(Interest::ColorGamut) {
+        if let Some(value) = color_gamut_media_query(&window).map(|q| q.value()) {
+            preferences.color_gamut = value;
+        }
+    }
+
     #[cfg(feature = "accent-color")]
     if interest.is(Interest::AccentColor) {
         if let Some(value) = accent_color::get_accent_color(&window) {
@@ -195,9 +348,120 @@ pub(crate) fn once_blocking(
         }
     }
 
+    #[cfg(feature = "forced-colors")]
+    if interest.is(Interest::ForcedColors) {
+        if let Some(value) = forced_colors_media_query(&window).map(|q| q.value()) {
+            preferences.forced_colors = value;
+        }
+    }
+
+    #[cfg(feature = "inverted-colors")]
+    if interest.is(Interest::InvertedColors) {
+        if let Some(value) = inverted_colors_media_query(&window).map(|q| q.value()) {
+            preferences.inverted_colors = value;
+        }
+    }
+
+    #[cfg(feature = "reduced-data")]
+    if interest.is(Interest::ReducedData) {
+        if let Some(value) = reduced_data_media_query(&window).map(|q| q.value()) {
+            preferences.reduced_data = value;
+        }
+    }
+
+    #[cfg(feature = "time-format")]
+    if interest.is(Interest::TimeFormat) {
+        preferences.time_format = time_format::get_time_format();
+    }
+
+    #[cfg(feature = "ui-scale-factor")]
+    if interest.is(Interest::UiScaleFactor) {
+        preferences.ui_scale_factor = ui_scale_factor::get_ui_scale_factor(&window);
+    }
+
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        preferences.system_palette = system_palette::get_system_palette(&window);
+    }
+
     Some(preferences)
 }
 
+// All of the preferences above are read through `window()`,
+// which is unavailable from a Web Worker, so that's the only thing that can make every
+// preference unsupported at once here. Beyond that, a couple of preferences additionally
+// depend on CSS features (`AccentColor`/system color keywords) that not every browser
+// engine implements, so those are probed individually via `CSS.supports()`.
+pub(crate) fn supported_interests() -> Interest {
+    if window().is_none() {
+        return Interest::default();
+    }
+
+    #[allow(unused_mut)]
+    let mut supported = Interest::default();
+
+    #[cfg(feature = "reduced-motion")]
+    {
+        supported = supported | Interest::ReducedMotion;
+    }
+    #[cfg(feature = "reduced-transparency")]
+    {
+        supported = supported | Interest::ReducedTransparency;
+    }
+    #[cfg(feature = "color-scheme")]
+    {
+        supported = supported | Interest::ColorScheme;
+    }
+    #[cfg(feature = "contrast")]
+    {
+        supported = supported | Interest::Contrast;
+    }
+    #[cfg(feature = "color-gamut")]
+    {
+        supported = supported | Interest::ColorGamut;
+    }
+    #[cfg(feature = "forced-colors")]
+    {
+        supported = supported | Interest::ForcedColors;
+    }
+    #[cfg(feature = "inverted-colors")]
+    {
+        supported = supported | Interest::InvertedColors;
+    }
+    #[cfg(feature = "reduced-data")]
+    {
+        supported = supported | Interest::ReducedData;
+    }
+    #[cfg(feature = "time-format")]
+    {
+        supported = supported | Interest::TimeFormat;
+    }
+    #[cfg(feature = "accent-color")]
+    {
+        supported = supported | Interest::AccentColor;
+    }
+    #[cfg(feature = "ui-scale-factor")]
+    {
+        supported = supported | Interest::UiScaleFactor;
+    }
+    #[cfg(feature = "system-palette")]
+    {
+        supported = supported | Interest::SystemPalette;
+    }
+
+    #[cfg(feature = "accent-color")]
+    if !accent_color::supports_accent_color() {
+        supported = supported.without(Interest::AccentColor);
+    }
+
+    #[cfg(feature = "system-palette")]
+    if !system_palette::supports_system_palette() {
+        supported = supported.without(Interest::SystemPalette);
+    }
+
+    supported
+}
+
 fn changes(
     seed: AvailablePreferences,
     receiver: mpsc::UnboundedReceiver<Preference>,
@@ -214,12 +478,26 @@ enum Preference {
     ColorScheme(ColorScheme),
     #[cfg(feature = "contrast")]
     Contrast(Contrast),
+    #[cfg(feature = "color-gamut")]
+    ColorGamut(ColorGamut),
     #[cfg(feature = "reduced-motion")]
     ReducedMotion(ReducedMotion),
     #[cfg(feature = "reduced-transparency")]
     ReducedTransparency(ReducedTransparency),
+    #[cfg(feature = "forced-colors")]
+    ForcedColors(ForcedColors),
+    #[cfg(feature = "inverted-colors")]
+    InvertedColors(InvertedColors),
+    #[cfg(feature = "reduced-data")]
+    ReducedData(ReducedData),
     #[cfg(feature = "accent-color")]
     AccentColor(AccentColor),
+    #[cfg(feature = "time-format")]
+    TimeFormat(TimeFormat),
+    #[cfg(feature = "ui-scale-factor")]
+    UiScaleFactor(UiScaleFactor),
+    #[cfg(feature = "system-palette")]
+    SystemPalette(SystemPalette),
 }
 
 impl Preference {
@@ -229,27 +507,61 @@ impl Preference {
             Preference::ColorScheme(v) => preferences.color_scheme = v,
             #[cfg(feature = "contrast")]
             Preference::Contrast(v) => preferences.contrast = v,
+            #[cfg(feature = "color-gamut")]
+            Preference::ColorGamut(v) => preferences.color_gamut = v,
             #[cfg(feature = "reduced-motion")]
             Preference::ReducedMotion(v) => preferences.reduced_motion = v,
             #[cfg(feature = "reduced-transparency")]
             Preference::ReducedTransparency(v) => preferences.reduced_transparency = v,
+            #[cfg(feature = "forced-colors")]
+            Preference::ForcedColors(v) => preferences.forced_colors = v,
+            #[cfg(feature = "inverted-colors")]
+            Preference::InvertedColors(v) => preferences.inverted_colors = v,
+            #[cfg(feature = "reduced-data")]
+            Preference::ReducedData(v) => preferences.reduced_data = v,
             #[cfg(feature = "accent-color")]
             Preference::AccentColor(v) => preferences.accent_color = v,
+            #[cfg(feature = "time-format")]
+            Preference::TimeFormat(v) => preferences.time_format = v,
+            #[cfg(feature = "ui-scale-factor")]
+            Preference::UiScaleFactor(v) => preferences.ui_scale_factor = v,
+            #[cfg(feature = "system-palette")]
+            Preference::SystemPalette(v) => preferences.system_palette = v,
         };
         preferences
     }
 }
 
 #[cfg(feature = "reduced-transparency")]
-fn prefers_reduced_transparency_query(
-    window: &web_sys::Window,
-) -> Option<bool::BooleanMediaQuery<'_, ReducedTransparency>> {
-    bool::BooleanMediaQuery::new(
-        window,
-        "(prefers-reduced-transparency: reduce)",
-        ReducedTransparency::Reduce,
-        ReducedTransparency::NoPreference,
-    )
+multi_value_media_query! {
+    prefers_reduced_transparency_query -> ReducedTransparency {
+        "(prefers-reduced-transparency: reduce)" => ReducedTransparency::Reduce,
+        _ => ReducedTransparency::NoPreference,
+    }
+}
+
+#[cfg(feature = "forced-colors")]
+multi_value_media_query! {
+    forced_colors_media_query -> ForcedColors {
+        "(forced-colors: active)" => ForcedColors::Active,
+        _ => ForcedColors::NoPreference,
+    }
+}
+
+#[cfg(feature = "inverted-colors")]
+multi_value_media_query! {
+    inverted_colors_media_query -> InvertedColors {
+        "(inverted-colors: inverted)" => InvertedColors::Inverted,
+        _ => InvertedColors::NoPreference,
+    }
+}
+
+#[cfg(feature = "reduced-data")]
+multi_value_media_query! {
+    reduced_data_media_query -> ReducedData {
+        "(prefers-reduced-data: reduce)" => ReducedData::Reduce,
+        _ => ReducedData::NoPreference,
+    }
 }
 
 #[cfg(feature = "reduced-motion")]
@@ -282,3 +594,16 @@ multi_value_media_query! {
         _ => ColorScheme::NoPreference,
     }
 }
+
+// `color-gamut` is a range feature: `(color-gamut: p3)` also matches a `rec2020` display,
+// and `(color-gamut: srgb)` matches both. List the queries widest-first so the broadest
+// gamut the display actually supports wins, instead of `value()` stopping at `srgb`.
+#[cfg(feature = "color-gamut")]
+multi_value_media_query! {
+    color_gamut_media_query -> ColorGamut {
+        "(color-gamut: rec2020)" => ColorGamut::Rec2020,
+        "(color-gamut: p3)" => ColorGamut::P3,
+        "(color-gamut: srgb)" => ColorGamut::Srgb,
+        _ => ColorGamut::NoPreference,
+    }
+}
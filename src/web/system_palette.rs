@@ -0,0 +1,102 @@
+use super::accent_color::{add_color_change_listener, get_color_from_computed_style, OffscreenElement};
+use super::event_listener::EventListenerGuard;
+use crate::{Srgba, SystemPalette};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{css, Window};
+
+type Setter = fn(&mut SystemPalette, Option<Srgba>);
+
+const SLOTS: &[(&str, Setter)] = &[
+    ("Canvas", |p, v| p.canvas = v),
+    ("CanvasText", |p, v| p.canvas_text = v),
+    ("LinkText", |p, v| p.link_text = v),
+    ("VisitedText", |p, v| p.visited_text = v),
+    ("Highlight", |p, v| p.highlight = v),
+    ("HighlightText", |p, v| p.highlight_text = v),
+    ("ButtonFace", |p, v| p.button_face = v),
+    ("ButtonText", |p, v| p.button_text = v),
+    ("Field", |p, v| p.field = v),
+    ("FieldText", |p, v| p.field_text = v),
+    ("GrayText", |p, v| p.gray_text = v),
+];
+
+/// Generalizes the `transitionstart` trick behind the accent color observer to a whole
+/// palette of CSS system colors: one offscreen element per tracked color, each
+/// independently watched via its own `color 0.001ms step-start` transition.
+pub(crate) struct SystemPaletteObserver {
+    _elements: Vec<OffscreenElement>,
+    _guards: Vec<EventListenerGuard>,
+}
+
+impl SystemPaletteObserver {
+    pub(crate) fn new(
+        window: &Window,
+        callback: impl FnMut(SystemPalette) + 'static,
+    ) -> Option<(Self, SystemPalette)> {
+        let palette = Rc::new(RefCell::new(SystemPalette::default()));
+        let callback = Rc::new(RefCell::new(callback));
+        let mut elements = Vec::with_capacity(SLOTS.len());
+        let mut guards = Vec::with_capacity(SLOTS.len());
+
+        for &(keyword, setter) in SLOTS {
+            let element = create_element(window, keyword)?;
+            setter(
+                &mut palette.borrow_mut(),
+                get_color_from_computed_style(window, &element),
+            );
+            let guard = add_color_change_listener(&element, {
+                let window = window.clone();
+                let element = element.clone();
+                let palette = palette.clone();
+                let callback = callback.clone();
+                move || {
+                    setter(
+                        &mut palette.borrow_mut(),
+                        get_color_from_computed_style(&window, &element),
+                    );
+                    (callback.borrow_mut())(*palette.borrow());
+                }
+            })
+            .ok()?;
+            elements.push(element);
+            guards.push(guard);
+        }
+
+        let initial = *palette.borrow();
+        Some((
+            Self {
+                _elements: elements,
+                _guards: guards,
+            },
+            initial,
+        ))
+    }
+}
+
+// `Canvas` is as old as CSS system colors get, so if a browser doesn't even
+// support that one keyword it's not going to support any of the others either.
+pub(crate) fn supports_system_palette() -> bool {
+    css::supports("color: Canvas").unwrap_or_default()
+}
+
+pub(crate) fn get_system_palette(window: &Window) -> SystemPalette {
+    let mut palette = SystemPalette::default();
+    for &(keyword, setter) in SLOTS {
+        if let Some(element) = create_element(window, keyword) {
+            setter(&mut palette, get_color_from_computed_style(window, &element));
+        }
+    }
+    palette
+}
+
+fn create_element(window: &Window, keyword: &'static str) -> Option<OffscreenElement> {
+    const COMMENT: &str = concat!(
+        "this element is used by the '",
+        env!("CARGO_PKG_NAME"),
+        "' crate to detect changes to a CSS system color"
+    );
+    let element = OffscreenElement::new(window, COMMENT)?;
+    element.style().set_property("color", keyword).ok()?;
+    Some(element)
+}
@@ -0,0 +1,29 @@
+use super::drop_on_main_thread::DropOnMainThread;
+use super::event_listener::{EventListenerGuard, EventTargetExt as _};
+use crate::TimeFormat;
+use js_sys::Intl;
+use wasm_bindgen::JsValue;
+use web_sys::{Event, Window};
+
+// There's no media query for this, so we derive it from `Intl.DateTimeFormat`
+// instead and re-read on `languagechange`, the only signal the web platform
+// gives us for a locale change.
+pub(crate) fn get_time_format() -> TimeFormat {
+    let options =
+        Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new()).resolved_options();
+    match js_sys::Reflect::get(&options, &JsValue::from_str("hour12")) {
+        Ok(value) if value.as_bool() == Some(true) => TimeFormat::Twelve,
+        Ok(value) if value.as_bool() == Some(false) => TimeFormat::TwentyFour,
+        _ => TimeFormat::NoPreference,
+    }
+}
+
+pub(crate) fn subscribe(
+    window: &Window,
+    mut callback: impl FnMut(TimeFormat) + 'static,
+) -> Option<DropOnMainThread<EventListenerGuard>> {
+    let guard = window
+        .add_event_listener("languagechange", move |_: Event| callback(get_time_format()))
+        .ok()?;
+    Some(DropOnMainThread::new(guard, window))
+}
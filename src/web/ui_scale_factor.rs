@@ -0,0 +1,60 @@
+use super::drop_on_main_thread::DropOnMainThread;
+use super::event_listener::{EventListenerGuard, EventTargetExt as _};
+use crate::UiScaleFactor;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{MediaQueryListEvent, Window};
+
+/// Observes `window.devicePixelRatio`.
+///
+/// There's no dedicated "devicePixelRatio changed" event, so per MDN's own
+/// recommendation we watch a `matchMedia("(resolution: <dpr>dppx)")` query instead.
+/// Such a query only fires its `change` event once, the moment the ratio crosses that
+/// threshold in either direction, so on every change we re-read the ratio and
+/// re-create the query at the new value to keep observing future changes.
+pub(crate) struct UiScaleFactorObserver {
+    _guard: Rc<RefCell<Option<DropOnMainThread<EventListenerGuard>>>>,
+}
+
+impl UiScaleFactorObserver {
+    pub(crate) fn new(
+        window: &Window,
+        callback: impl FnMut(UiScaleFactor) + Clone + 'static,
+    ) -> Option<(Self, UiScaleFactor)> {
+        let slot = Rc::new(RefCell::new(None));
+        let guard = watch(window.clone(), slot.clone(), callback)?;
+        *slot.borrow_mut() = Some(guard);
+        Some((Self { _guard: slot }, get_ui_scale_factor(window)))
+    }
+}
+
+pub(crate) fn get_ui_scale_factor(window: &Window) -> UiScaleFactor {
+    UiScaleFactor(Some(window.device_pixel_ratio()))
+}
+
+fn watch(
+    window: Window,
+    slot: Rc<RefCell<Option<DropOnMainThread<EventListenerGuard>>>>,
+    mut callback: impl FnMut(UiScaleFactor) + Clone + 'static,
+) -> Option<DropOnMainThread<EventListenerGuard>> {
+    let ratio = window.device_pixel_ratio();
+    let query = window
+        .match_media(&format!("(resolution: {ratio}dppx)"))
+        .ok()
+        .flatten()?;
+    let guard = query
+        .add_event_listener("change", {
+            let window = window.clone();
+            move |_event: MediaQueryListEvent| {
+                callback(get_ui_scale_factor(&window));
+                // The threshold we were watching only fires once, so re-arm at the
+                // new ratio to keep observing future changes. The previous guard
+                // (and its now-fired listener) is dropped once replaced below.
+                if let Some(next) = watch(window.clone(), slot.clone(), callback.clone()) {
+                    *slot.borrow_mut() = Some(next);
+                }
+            }
+        })
+        .ok()?;
+    Some(DropOnMainThread::new(guard, &window))
+}
@@ -1,21 +1,33 @@
-use std::marker::PhantomData;
 use windows::core::Error;
+use windows::Win32::Foundation::{RPC_E_CHANGED_MODE, S_FALSE};
 use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT};
 
-pub(crate) struct ComThreadGuard(PhantomData<()>);
+pub(crate) struct ComThreadGuard {
+    /// Whether we're responsible for the matching `CoUninitialize`. `S_FALSE`
+    /// still increments the apartment's init refcount like a fresh call would,
+    /// so it must still be balanced by a `CoUninitialize` of our own; only
+    /// `RPC_E_CHANGED_MODE`, which doesn't initialize anything, leaves tearing
+    /// down to whoever set the apartment up.
+    owns_com: bool,
+}
 
 impl ComThreadGuard {
     pub(crate) fn new(coinit: COINIT) -> Result<Self, Error> {
-        // SAFETY: Our drop impl ensures that COM is uninitialized.
-        let result = unsafe { CoInitializeEx(None, coinit) };
-        result.map(|| Self(PhantomData))
+        // SAFETY: Our drop impl only calls `CoUninitialize` when we're the ones
+        // who actually initialized COM on this thread.
+        match unsafe { CoInitializeEx(None, coinit) } {
+            RPC_E_CHANGED_MODE => Ok(Self { owns_com: false }),
+            hr => hr.map(|| Self { owns_com: true }),
+        }
     }
 }
 
 impl Drop for ComThreadGuard {
     fn drop(&mut self) {
-        // SAFETY: Instances of this type are only created
-        // when COM was successfully initialized.
-        unsafe { CoUninitialize() };
+        if self.owns_com {
+            // SAFETY: Instances with `owns_com` set are only created when we
+            // successfully initialized COM on this thread ourselves.
+            unsafe { CoUninitialize() };
+        }
     }
 }
@@ -2,12 +2,18 @@
 //! to intercept messages (we care about `WM_SETTINGCHANGE` in particular).
 //! This is a lot easier (and involves a lot less unsafe code) than setting
 //! up our own hidden window and event loop.
+//!
+//! Hosts that already pump their own messages (e.g. winit's
+//! `EventLoopBuilderExtWindows::with_msg_hook`) can skip the global hook entirely
+//! and feed messages to us directly through [`on_win_message`]. See its doc
+//! comment for details.
 
 use crate::callback_utils::{CallbackHandle, Callbacks};
 use crate::windows::main_thread::main_thread_id;
 use std::error::Error;
 use std::mem;
 use std::panic::catch_unwind;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock, Weak};
 use windows::core::Owned;
 use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
@@ -17,33 +23,60 @@ use windows::Win32::UI::WindowsAndMessaging::{
 
 /// Registers a windows hook that is automatically unregistered when
 /// the returned guard is dropped.
+///
+/// If a host has already called [`on_win_message`] by the time this runs, we
+/// assume it intends to keep feeding us messages that way and skip installing
+/// our own `WH_CALLWNDPROC` hook, so we don't stack a second global hook on
+/// top of the host's own message pump.
 pub(crate) fn register_windows_hook(
     hook: CallbackFn,
 ) -> Result<WindowsHookGuard, Box<dyn Error>> {
     let callback = register_callback(hook)?;
-    let hook = register_hook()?;
+    let hook = if EXTERNAL_HOOK_ACTIVE.load(Ordering::Relaxed) {
+        None
+    } else {
+        Some(register_hook()?)
+    };
     Ok(WindowsHookGuard((hook, callback)))
 }
 
-pub(crate) type CallbackFn = Box<dyn Fn(CWPSTRUCT) + Send + Sync>;
+/// Feeds a Win32 message observed by a host-owned message hook (such as
+/// winit's `EventLoopBuilderExtWindows::with_msg_hook`) into the same callback
+/// registry that mundy's own `WH_CALLWNDPROC` hook uses, so `register_windows_hook`
+/// can skip installing a second, process-wide hook.
+///
+/// Only `WM_SETTINGCHANGE` carries information mundy acts on, so it's enough (and
+/// cheapest) to forward just that one, but forwarding every message your hook sees
+/// is also harmless.
+pub fn on_win_message(message: u32, wparam: WPARAM, lparam: LPARAM) {
+    EXTERNAL_HOOK_ACTIVE.store(true, Ordering::Relaxed);
+    dispatch(message, wparam, lparam);
+}
+
+pub(crate) type CallbackFn = Box<dyn Fn(u32, WPARAM, LPARAM) + Send + Sync>;
 
 pub(crate) struct WindowsHookGuard(
     #[expect(dead_code, reason = "used to free resources on drop")]
-    (Arc<HookHandle>, CallbackGuard),
+    (Option<Arc<HookHandle>>, CallbackGuard),
 );
 
 static CALLBACKS: RwLock<Callbacks<CallbackFn>> = RwLock::new(Callbacks::new());
+static EXTERNAL_HOOK_ACTIVE: AtomicBool = AtomicBool::new(false);
 
-unsafe extern "system" fn hook_proc(ncode: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
-    // SAFETY: lParam: A pointer to a CWPSTRUCT structure that contains details about the message.
-    let data = unsafe { *(lparam.0 as *const CWPSTRUCT) };
+fn dispatch(message: u32, wparam: WPARAM, lparam: LPARAM) {
     _ = catch_unwind(|| {
         if let Ok(callbacks) = CALLBACKS.read() {
             for callback in callbacks.iter() {
-                callback(data);
+                callback(message, wparam, lparam);
             }
         }
     });
+}
+
+unsafe extern "system" fn hook_proc(ncode: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    // SAFETY: lParam: A pointer to a CWPSTRUCT structure that contains details about the message.
+    let data = unsafe { *(lparam.0 as *const CWPSTRUCT) };
+    dispatch(data.message, data.wParam, data.lParam);
     unsafe { CallNextHookEx(None, ncode, wparam, lparam) }
 }
 
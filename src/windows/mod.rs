@@ -4,39 +4,85 @@ use crate::ColorScheme;
 use crate::Contrast;
 #[cfg(feature = "double-click-interval")]
 use crate::DoubleClickInterval;
+#[cfg(feature = "caret-blink-interval")]
+use crate::CaretBlinkInterval;
+#[cfg(feature = "text-scale-factor")]
+use crate::TextScaleFactor;
 #[cfg(feature = "reduced-motion")]
 use crate::ReducedMotion;
 #[cfg(feature = "reduced-transparency")]
 use crate::ReducedTransparency;
+#[cfg(feature = "forced-colors")]
+use crate::ForcedColors;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "system-palette")]
+use crate::SystemPalette;
+#[cfg(feature = "system-colors")]
+use crate::SystemColors;
+#[cfg(feature = "font-rendering")]
+use crate::{Antialiasing, FontRendering, Hinting, SubpixelOrder};
+#[cfg(feature = "color-gamut")]
+use crate::ColorGamut;
+#[cfg(any(
+    feature = "accent-color",
+    feature = "system-colors",
+    feature = "system-palette"
+))]
+use crate::Srgba;
 #[cfg(feature = "accent-color")]
-use crate::{AccentColor, Srgba};
+use crate::AccentColor;
 use crate::{AvailablePreferences, Interest};
 #[cfg(feature = "_winrt")]
 use com_thread::ComThreadGuard;
 use futures_channel::mpsc;
 use futures_lite::{stream, Stream, StreamExt as _};
 use hook::{register_windows_hook, WindowsHookGuard};
+pub(crate) use hook::on_win_message;
 use pin_project_lite::pin_project;
 use std::sync::mpsc as std_mpsc;
 use std::thread;
-#[cfg(feature = "double-click-interval")]
 use std::time::Duration;
 #[cfg(feature = "_winrt")]
 use windows::Win32::System::Com::COINIT_MULTITHREADED;
 #[cfg(feature = "double-click-interval")]
 use windows::Win32::UI::Input::KeyboardAndMouse::GetDoubleClickTime;
+#[cfg(feature = "caret-blink-interval")]
+use windows::Win32::UI::Input::KeyboardAndMouse::GetCaretBlinkTime;
+#[cfg(feature = "contrast")]
+use windows::Win32::UI::WindowsAndMessaging::{
+    HCF_HIGHCONTRASTON, HIGHCONTRASTW, SPI_GETHIGHCONTRAST, SystemParametersInfoW,
+};
 use windows::Win32::UI::WindowsAndMessaging::WM_SETTINGCHANGE;
-#[cfg(any(feature = "color-scheme", feature = "accent-color"))]
+#[cfg(feature = "font-rendering")]
+use windows::Win32::Foundation::BOOL;
+#[cfg(feature = "font-rendering")]
+use windows::Win32::UI::WindowsAndMessaging::{
+    FE_FONTSMOOTHINGCLEARTYPE, FE_FONTSMOOTHINGORIENTATIONRGB, SPI_GETFONTSMOOTHING,
+    SPI_GETFONTSMOOTHINGORIENTATION, SPI_GETFONTSMOOTHINGTYPE, SYSTEM_PARAMETERS_INFO_ACTION,
+};
+#[cfg(feature = "color-gamut")]
+use windows::Graphics::Display::{AdvancedColorKind, DisplayInformation};
+#[cfg(feature = "inverted-colors")]
+use windows::core::{w, PCWSTR};
+#[cfg(feature = "inverted-colors")]
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+#[cfg(feature = "system-palette")]
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetSysColor, COLOR_BTNFACE, COLOR_BTNTEXT, COLOR_GRAYTEXT, COLOR_HIGHLIGHT,
+    COLOR_HIGHLIGHTTEXT, COLOR_HOTLIGHT, COLOR_WINDOW, COLOR_WINDOWTEXT,
+};
+#[cfg(any(feature = "color-scheme", feature = "accent-color", feature = "system-colors"))]
 use windows::UI::Color;
-#[cfg(feature = "contrast")]
-use windows::UI::ViewManagement::AccessibilitySettings;
-#[cfg(any(feature = "color-scheme", feature = "accent-color"))]
+#[cfg(any(feature = "color-scheme", feature = "accent-color", feature = "system-colors"))]
 use windows::UI::ViewManagement::UIColorType;
 #[cfg(any(
     feature = "color-scheme",
     feature = "accent-color",
     feature = "reduced-motion",
-    feature = "reduced-transparency"
+    feature = "reduced-transparency",
+    feature = "system-colors",
+    feature = "text-scale-factor"
 ))]
 use windows::UI::ViewManagement::UISettings;
 
@@ -119,22 +165,54 @@ fn com_thread(
         match message {
             Message::Shutdown => break,
             Message::WM_SETTINGCHANGE => {
+                if wait_out_settingchange_burst(&msg_rx) {
+                    break;
+                }
+                // Whichever `AvailablePreferences` comes out of this is deduped against
+                // the previous one further up the stack (see `Dedup` in `lib.rs`), so a
+                // burst that didn't actually change anything we care about is silently
+                // dropped rather than waking the consumer for no reason.
                 _ = sender.unbounded_send(read_preferences(&settings, interest));
             }
         }
     }
 }
 
+// A single theme change fires `WM_SETTINGCHANGE` once per individual setting it touches,
+// so a dozen of these can arrive back-to-back for one user action. Rather than doing a
+// `read_preferences` (several blocking WinRT round-trips) per message, drain whatever's
+// already queued and then wait out a short quiet window for stragglers, so the whole
+// burst collapses into a single read.
+//
+// Returns `true` if a `Shutdown` message was seen while doing so.
+const SETTINGCHANGE_QUIET_WINDOW: Duration = Duration::from_millis(75);
+
+fn wait_out_settingchange_burst(msg_rx: &std_mpsc::Receiver<Message>) -> bool {
+    loop {
+        match msg_rx.try_recv() {
+            Ok(Message::Shutdown) => return true,
+            Ok(Message::WM_SETTINGCHANGE) => continue,
+            Err(std_mpsc::TryRecvError::Disconnected) => return true,
+            Err(std_mpsc::TryRecvError::Empty) => {}
+        }
+        match msg_rx.recv_timeout(SETTINGCHANGE_QUIET_WINDOW) {
+            Ok(Message::Shutdown) => return true,
+            Ok(Message::WM_SETTINGCHANGE) => continue,
+            Err(_) => return false,
+        }
+    }
+}
+
 struct Settings {
     #[cfg(any(
         feature = "color-scheme",
         feature = "accent-color",
         feature = "reduced-motion",
-        feature = "reduced-transparency"
+        feature = "reduced-transparency",
+        feature = "system-colors",
+        feature = "text-scale-factor"
     ))]
     ui: Option<UISettings>,
-    #[cfg(feature = "contrast")]
-    accessibility: Option<AccessibilitySettings>,
 }
 
 impl Settings {
@@ -144,18 +222,18 @@ impl Settings {
                 feature = "color-scheme",
                 feature = "accent-color",
                 feature = "reduced-motion",
-                feature = "reduced-transparency"
+                feature = "reduced-transparency",
+                feature = "system-colors",
+                feature = "text-scale-factor"
             ))]
             ui: UISettings::new().ok(),
-            #[cfg(feature = "contrast")]
-            accessibility: AccessibilitySettings::new().ok(),
         }
     }
 }
 
 fn register_wm_settingchange_hook(tx: std_mpsc::Sender<Message>) -> Option<WindowsHookGuard> {
-    let result = register_windows_hook(Box::new(move |data| {
-        if data.message == WM_SETTINGCHANGE {
+    let result = register_windows_hook(Box::new(move |message, _wparam, _lparam| {
+        if message == WM_SETTINGCHANGE {
             _ = tx.send(Message::WM_SETTINGCHANGE);
         }
     }));
@@ -192,10 +270,8 @@ fn read_preferences(
     }
 
     #[cfg(feature = "contrast")]
-    if let Some(accessibility) = &settings.accessibility {
-        if interest.is(Interest::Contrast) {
-            preferences.contrast = read_contrast(accessibility);
-        }
+    if interest.is(Interest::Contrast) {
+        preferences.contrast = read_contrast();
     }
 
     #[cfg(feature = "accent-color")]
@@ -219,11 +295,55 @@ fn read_preferences(
         }
     }
 
+    #[cfg(feature = "forced-colors")]
+    if interest.is(Interest::ForcedColors) {
+        preferences.forced_colors = read_forced_colors();
+    }
+
     #[cfg(feature = "double-click-interval")]
     if interest.is(Interest::DoubleClickInterval) {
         preferences.double_click_interval = read_double_click_time();
     }
 
+    #[cfg(feature = "system-colors")]
+    if let Some(ui) = &settings.ui {
+        if interest.is(Interest::SystemColors) {
+            preferences.system_colors = read_system_colors(ui);
+        }
+    }
+
+    #[cfg(feature = "caret-blink-interval")]
+    if interest.is(Interest::CaretBlinkInterval) {
+        preferences.caret_blink_interval = read_caret_blink_interval();
+    }
+
+    #[cfg(feature = "text-scale-factor")]
+    if let Some(ui) = &settings.ui {
+        if interest.is(Interest::TextScaleFactor) {
+            preferences.text_scale_factor = read_text_scale_factor(ui);
+        }
+    }
+
+    #[cfg(feature = "font-rendering")]
+    if interest.is(Interest::FontRendering) {
+        preferences.font_rendering = read_font_rendering();
+    }
+
+    #[cfg(feature = "color-gamut")]
+    if interest.is(Interest::ColorGamut) {
+        preferences.color_gamut = read_color_gamut();
+    }
+
+    #[cfg(feature = "inverted-colors")]
+    if interest.is(Interest::InvertedColors) {
+        preferences.inverted_colors = read_inverted_colors();
+    }
+
+    #[cfg(feature = "system-palette")]
+    if interest.is(Interest::SystemPalette) {
+        preferences.system_palette = read_system_palette();
+    }
+
     preferences
 }
 
@@ -250,31 +370,87 @@ fn read_accent_color(settings: &UISettings) -> AccentColor {
     }
 
     let accent = try_settings_result!(settings.GetColorValue(UIColorType::Accent));
-    AccentColor(Some(to_srgba(accent)))
+    AccentColor(Some(to_srgba(accent).into()))
 }
 
-// TODO: Windows technically supports "less" and "custom" contrast
-// but I'm not sure which API to call.
+// `AccessibilitySettings::HighContrast()` only tells us whether high contrast is on, not
+// which of the built-in schemes (or a user-defined one) is active, so we go around it and
+// call `SystemParametersInfoW(SPI_GETHIGHCONTRAST, ..)` directly for the scheme name instead.
 #[cfg(feature = "contrast")]
-fn read_contrast(settings: &AccessibilitySettings) -> Contrast {
-    let high_contrast = try_settings_result!(settings.HighContrast());
-    if high_contrast {
-        Contrast::More
+fn read_high_contrast() -> Option<(bool, String)> {
+    let mut info = HIGHCONTRASTW {
+        cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            info.cbSize,
+            Some(&mut info as *mut HIGHCONTRASTW as *mut _),
+            Default::default(),
+        )
+    }
+    .ok()?;
+
+    let scheme = if info.lpszDefaultScheme.is_null() {
+        String::new()
     } else {
-        Contrast::NoPreference
+        unsafe { info.lpszDefaultScheme.to_string() }.unwrap_or_default()
+    };
+    Some((info.dwFlags.contains(HCF_HIGHCONTRASTON), scheme))
+}
+
+#[cfg(feature = "contrast")]
+fn read_contrast() -> Contrast {
+    let Some((enabled, scheme)) = read_high_contrast() else {
+        return Contrast::NoPreference;
+    };
+    if !enabled {
+        return Contrast::NoPreference;
+    }
+    // The four built-in schemes all represent the "standard" inverted high-contrast
+    // look; anything else with a non-empty name is a user-authored custom theme.
+    match scheme.as_str() {
+        "" | "High Contrast #1" | "High Contrast #2" | "High Contrast Black"
+        | "High Contrast White" => Contrast::More,
+        _ => Contrast::Custom,
     }
 }
 
+#[cfg(feature = "forced-colors")]
+fn read_forced_colors() -> ForcedColors {
+    match read_high_contrast() {
+        Some((true, _)) => ForcedColors::Active,
+        _ => ForcedColors::NoPreference,
+    }
+}
+
+#[cfg(feature = "color-scheme")]
+fn is_color_light(color: &Color) -> bool {
+    ((5 * color.G as u16) + (2 * color.R as u16) + color.B as u16) > (8 * 128)
+}
+
 // This is what's recommended by the official docs:
 // <https://learn.microsoft.com/en-us/windows/apps/desktop/modernize/ui/apply-windows-themes>
 #[cfg(feature = "color-scheme")]
 fn read_color_scheme(settings: &UISettings) -> ColorScheme {
-    let foreground = try_settings_result!(settings.GetColorValue(UIColorType::Foreground));
-
-    fn is_color_light(color: &Color) -> bool {
-        ((5 * color.G as u16) + (2 * color.R as u16) + color.B as u16) > (8 * 128)
+    // During a Black/White high-contrast session `Foreground` stays pinned near-white
+    // or near-black regardless of which of the two is active, so fall back to
+    // `Background` instead to keep this in sync with `Contrast`.
+    #[cfg(feature = "contrast")]
+    if let Some((true, scheme)) = read_high_contrast() {
+        if scheme == "High Contrast Black" || scheme == "High Contrast White" {
+            let background =
+                try_settings_result!(settings.GetColorValue(UIColorType::Background));
+            return if is_color_light(&background) {
+                ColorScheme::Light
+            } else {
+                ColorScheme::Dark
+            };
+        }
     }
 
+    let foreground = try_settings_result!(settings.GetColorValue(UIColorType::Foreground));
     if is_color_light(&foreground) {
         ColorScheme::Dark
     } else {
@@ -307,3 +483,302 @@ fn read_double_click_time() -> DoubleClickInterval {
     let millis = unsafe { GetDoubleClickTime() };
     DoubleClickInterval(Some(Duration::from_millis(millis as u64)))
 }
+
+// `GetCaretBlinkTime` reuses the same `WM_SETTINGCHANGE` hook as `GetDoubleClickTime` above,
+// since both are plain `user32` settings with no dedicated change notification of their own.
+#[cfg(feature = "caret-blink-interval")]
+fn read_caret_blink_interval() -> CaretBlinkInterval {
+    // `GetCaretBlinkTime` returns `INFINITE` (`u32::MAX`) when the caret doesn't blink at all.
+    let millis = unsafe { GetCaretBlinkTime() };
+    if millis == u32::MAX {
+        CaretBlinkInterval::Disabled
+    } else {
+        CaretBlinkInterval::Interval(Duration::from_millis(millis as u64))
+    }
+}
+
+#[cfg(feature = "text-scale-factor")]
+fn read_text_scale_factor(settings: &UISettings) -> TextScaleFactor {
+    let factor = try_settings_result!(settings.TextScaleFactor());
+    TextScaleFactor(Some(factor))
+}
+
+// Windows doesn't expose a separate, user-configurable hinting level the way fontconfig
+// does (ClearType/standard smoothing implies hinting, there's just no further detail to
+// read), so `hinting` is always `NoPreference` here.
+#[cfg(feature = "font-rendering")]
+fn read_font_rendering() -> FontRendering {
+    let Some(smoothing_enabled) = read_spi_bool(SPI_GETFONTSMOOTHING) else {
+        return FontRendering::default();
+    };
+    if !smoothing_enabled {
+        return FontRendering {
+            antialiasing: Antialiasing::None,
+            hinting: Hinting::NoPreference,
+            subpixel_order: SubpixelOrder::None,
+        };
+    }
+
+    let is_cleartype = read_spi_u32(SPI_GETFONTSMOOTHINGTYPE) == Some(FE_FONTSMOOTHINGCLEARTYPE);
+    if !is_cleartype {
+        return FontRendering {
+            antialiasing: Antialiasing::Grayscale,
+            hinting: Hinting::NoPreference,
+            subpixel_order: SubpixelOrder::None,
+        };
+    }
+
+    let subpixel_order = match read_spi_u32(SPI_GETFONTSMOOTHINGORIENTATION) {
+        Some(orientation) if orientation == FE_FONTSMOOTHINGORIENTATIONRGB => SubpixelOrder::Rgb,
+        Some(_) => SubpixelOrder::Bgr,
+        None => SubpixelOrder::NoPreference,
+    };
+    FontRendering {
+        antialiasing: Antialiasing::Subpixel,
+        hinting: Hinting::NoPreference,
+        subpixel_order,
+    }
+}
+
+#[cfg(feature = "font-rendering")]
+fn read_spi_bool(action: SYSTEM_PARAMETERS_INFO_ACTION) -> Option<bool> {
+    let mut value = BOOL::default();
+    unsafe {
+        SystemParametersInfoW(action, 0, Some(&mut value as *mut BOOL as *mut _), Default::default())
+    }
+    .ok()?;
+    Some(value.as_bool())
+}
+
+#[cfg(feature = "font-rendering")]
+fn read_spi_u32(action: SYSTEM_PARAMETERS_INFO_ACTION) -> Option<u32> {
+    let mut value = 0u32;
+    unsafe {
+        SystemParametersInfoW(action, 0, Some(&mut value as *mut u32 as *mut _), Default::default())
+    }
+    .ok()?;
+    Some(value)
+}
+
+// `DisplayInformation` only distinguishes standard dynamic range, wide color gamut and
+// high dynamic range, it doesn't expose the exact gamut primaries, so `ColorGamut::Rec2020`
+// here is a stand-in for "this display does HDR", not a guarantee the full Rec. 2020
+// gamut is covered.
+#[cfg(feature = "color-gamut")]
+fn read_color_gamut() -> ColorGamut {
+    let Ok(display_info) = DisplayInformation::GetForCurrentView() else {
+        return ColorGamut::NoPreference;
+    };
+    let Ok(advanced_color_info) = display_info.GetAdvancedColorInfo() else {
+        return ColorGamut::NoPreference;
+    };
+    match advanced_color_info.CurrentAdvancedColorKind() {
+        Ok(AdvancedColorKind::HighDynamicRange) => ColorGamut::Rec2020,
+        Ok(AdvancedColorKind::WideColorGamut) => ColorGamut::P3,
+        Ok(_) => ColorGamut::Srgb,
+        Err(_) => ColorGamut::NoPreference,
+    }
+}
+
+// The "Color Filters" accessibility feature isn't exposed through `SystemParametersInfo`
+// or WinRT, only through its own registry key. `FilterType` 1 ("Invert") and 2
+// ("Grayscale Inverted") both invert the display; the other filter types (grayscale,
+// the color-blindness simulations) don't.
+#[cfg(feature = "inverted-colors")]
+fn read_inverted_colors() -> InvertedColors {
+    let active = read_color_filtering_dword(w!("Active")) == Some(1);
+    let filter_type = read_color_filtering_dword(w!("FilterType"));
+    if active && matches!(filter_type, Some(1) | Some(2)) {
+        InvertedColors::Inverted
+    } else {
+        InvertedColors::NoPreference
+    }
+}
+
+#[cfg(feature = "inverted-colors")]
+fn read_color_filtering_dword(value_name: PCWSTR) -> Option<u32> {
+    let mut value = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!(r"Software\Microsoft\ColorFiltering"),
+            value_name,
+            RRF_RT_REG_DWORD,
+            None,
+            Some(&mut value as *mut u32 as *mut _),
+            Some(&mut size),
+        )
+    }
+    .ok()?;
+    Some(value)
+}
+
+/// Reports which preferences can actually be read back on this system, as opposed to
+/// those that are merely compiled in via feature flags.
+///
+/// `double-click-interval`, `caret-blink-interval`, `contrast`, `forced-colors`,
+/// `font-rendering`, `system-palette` and `inverted-colors` are plain `user32` or registry
+/// calls that are always available. `color-gamut` (read through
+/// `DisplayInformation::GetForCurrentView`) is never reported here. Everything else goes
+/// through `UISettings`, which
+/// requires a working WinRT/COM apartment and can fail to construct on older Windows
+/// versions or in processes that can't initialize it.
+pub(crate) fn supported_interests() -> Interest {
+    let (tx, rx) = std_mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("{} COM thread", env!("CARGO_PKG_NAME")))
+        .spawn(move || {
+            #[cfg(feature = "_winrt")]
+            let _guard = ComThreadGuard::new(COINIT_MULTITHREADED).ok();
+            _ = tx.send(settings_supported_interests(&Settings::new()));
+        });
+    match spawned {
+        Ok(handle) => {
+            let interest = rx.recv().unwrap_or_else(|_| always_supported_interests());
+            _ = handle.join();
+            interest
+        }
+        Err(_) => always_supported_interests(),
+    }
+}
+
+fn always_supported_interests() -> Interest {
+    #[allow(unused_mut)]
+    let mut interest = Interest::default();
+    #[cfg(feature = "double-click-interval")]
+    {
+        interest = interest | Interest::DoubleClickInterval;
+    }
+    #[cfg(feature = "caret-blink-interval")]
+    {
+        interest = interest | Interest::CaretBlinkInterval;
+    }
+    #[cfg(feature = "contrast")]
+    {
+        interest = interest | Interest::Contrast;
+    }
+    #[cfg(feature = "forced-colors")]
+    {
+        interest = interest | Interest::ForcedColors;
+    }
+    #[cfg(feature = "font-rendering")]
+    {
+        interest = interest | Interest::FontRendering;
+    }
+    #[cfg(feature = "system-palette")]
+    {
+        interest = interest | Interest::SystemPalette;
+    }
+    #[cfg(feature = "inverted-colors")]
+    {
+        interest = interest | Interest::InvertedColors;
+    }
+    interest
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "color-scheme",
+        feature = "accent-color",
+        feature = "reduced-motion",
+        feature = "reduced-transparency",
+        feature = "system-colors",
+        feature = "text-scale-factor"
+    )),
+    allow(unused_variables)
+)]
+fn settings_supported_interests(settings: &Settings) -> Interest {
+    #[allow(unused_mut)]
+    let mut interest = always_supported_interests();
+    #[cfg(any(
+        feature = "color-scheme",
+        feature = "accent-color",
+        feature = "reduced-motion",
+        feature = "reduced-transparency",
+        feature = "system-colors",
+        feature = "text-scale-factor"
+    ))]
+    if settings.ui.is_some() {
+        #[cfg(feature = "color-scheme")]
+        {
+            interest = interest | Interest::ColorScheme;
+        }
+        #[cfg(feature = "accent-color")]
+        {
+            interest = interest | Interest::AccentColor;
+        }
+        #[cfg(feature = "reduced-motion")]
+        {
+            interest = interest | Interest::ReducedMotion;
+        }
+        #[cfg(feature = "reduced-transparency")]
+        {
+            interest = interest | Interest::ReducedTransparency;
+        }
+        #[cfg(feature = "system-colors")]
+        {
+            interest = interest | Interest::SystemColors;
+        }
+        #[cfg(feature = "text-scale-factor")]
+        {
+            interest = interest | Interest::TextScaleFactor;
+        }
+    }
+    interest
+}
+
+// `UIColorType` only standardizes `Background`/`Foreground` and the accent ramp;
+// there's no dedicated "separator" or "placeholder text" entry to call into, so
+// those slots are left unset on Windows, as is `selected_content_background`.
+#[cfg(feature = "system-colors")]
+fn read_system_colors(settings: &UISettings) -> SystemColors {
+    fn to_srgba(color: Color) -> Srgba {
+        Srgba::from_u8_array([color.R, color.G, color.B, color.A])
+    }
+
+    let label = settings
+        .GetColorValue(UIColorType::Foreground)
+        .ok()
+        .map(to_srgba);
+    let control_background = settings
+        .GetColorValue(UIColorType::Background)
+        .ok()
+        .map(to_srgba);
+    SystemColors {
+        label,
+        control_background,
+        selected_content_background: None,
+        separator: None,
+        placeholder_text: None,
+    }
+}
+
+// `GetSysColor` has no dedicated "visited link" or "input field" slot (those are app
+// styling choices on Windows, not a system color), so those two are left unset.
+#[cfg(feature = "system-palette")]
+fn read_system_palette() -> SystemPalette {
+    fn to_srgba(index: windows::Win32::UI::WindowsAndMessaging::SYS_COLOR_INDEX) -> Srgba {
+        let color = unsafe { GetSysColor(index) };
+        Srgba::from_u8_array([
+            (color & 0xff) as u8,
+            ((color >> 8) & 0xff) as u8,
+            ((color >> 16) & 0xff) as u8,
+            255,
+        ])
+    }
+
+    SystemPalette {
+        canvas: Some(to_srgba(COLOR_WINDOW)),
+        canvas_text: Some(to_srgba(COLOR_WINDOWTEXT)),
+        link_text: Some(to_srgba(COLOR_HOTLIGHT)),
+        visited_text: None,
+        highlight: Some(to_srgba(COLOR_HIGHLIGHT)),
+        highlight_text: Some(to_srgba(COLOR_HIGHLIGHTTEXT)),
+        button_face: Some(to_srgba(COLOR_BTNFACE)),
+        button_text: Some(to_srgba(COLOR_BTNTEXT)),
+        field: None,
+        field_text: None,
+        gray_text: Some(to_srgba(COLOR_GRAYTEXT)),
+    }
+}